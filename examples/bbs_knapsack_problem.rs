@@ -1,6 +1,6 @@
 use std::time::Duration;
 
-use tree_traversal::traversal::functional::bbs;
+use tree_traversal::{Maximize, traversal::functional::bbs};
 
 type Node = Vec<bool>;
 fn main() {
@@ -50,10 +50,10 @@ fn main() {
     let lower_bound_fn = |n: &Node| {
         let current_profit = total_profit(n);
         let max_remained_profit: u32 = profits[n.len()..].iter().sum();
-        Some(u32::MAX - (current_profit + max_remained_profit))
+        Some(Maximize(current_profit + max_remained_profit))
     };
 
-    let cost_fn = |n: &Node| Some(u32::MAX - total_profit(n));
+    let cost_fn = |n: &Node| Some(Maximize(total_profit(n)));
 
     let leaf_check_fn = |n: &Node| n.len() == total_items;
     let max_ops = usize::MAX;
@@ -69,7 +69,7 @@ fn main() {
         time_limit,
     )
     .expect("BBS should find a valid knapsack solution");
-    let cost = u32::MAX - cost;
+    let profit = cost.into_inner();
 
-    dbg!((best_node, cost));
+    dbg!((best_node, profit));
 }
@@ -1,18 +1,17 @@
-use std::rc::Rc;
-
 use tree_traversal::{
+    Maximize,
     node::{LowerBound, TreeNode},
     traversal::BranchAndBoundTraversal,
 };
 
-struct Node {
+struct Node<'a> {
     items: Vec<bool>,
     capacity: u32,
-    weights: Rc<[u32]>,
-    profits: Rc<[u32]>,
+    weights: &'a [u32],
+    profits: &'a [u32],
 }
 
-impl Node {
+impl Node<'_> {
     fn total_profit(&self) -> u32 {
         self.items
             .iter()
@@ -29,17 +28,17 @@ impl Node {
     }
 }
 
-impl LowerBound for Node {
-    type Cost = u32;
+impl LowerBound for Node<'_> {
+    type Cost = Maximize<u32>;
 
     fn cost_lb(&self) -> Option<Self::Cost> {
         let max_profit = self.max_profit();
-        Some(u32::MAX - max_profit)
+        Some(Maximize(max_profit))
     }
 }
 
-impl TreeNode for Node {
-    type Cost = u32;
+impl TreeNode for Node<'_> {
+    type Cost = Maximize<u32>;
 
     fn is_leaf(&self) -> bool {
         self.profits.len() == self.items.len()
@@ -67,8 +66,8 @@ impl TreeNode for Node {
             children.push(Node {
                 items: c1,
                 capacity: self.capacity,
-                weights: self.weights.clone(),
-                profits: self.profits.clone(),
+                weights: self.weights,
+                profits: self.profits,
             });
         }
 
@@ -77,8 +76,8 @@ impl TreeNode for Node {
         children.push(Node {
             items: c2,
             capacity: self.capacity,
-            weights: self.weights.clone(),
-            profits: self.profits.clone(),
+            weights: self.weights,
+            profits: self.profits,
         });
 
         children
@@ -86,7 +85,7 @@ impl TreeNode for Node {
 
     fn cost(&self) -> Option<Self::Cost> {
         let profit = self.total_profit();
-        Some(u32::MAX - profit)
+        Some(Maximize(profit))
     }
 }
 
@@ -98,8 +97,8 @@ fn main() {
     let root_node = Node {
         items: vec![],
         capacity,
-        weights: Rc::new(weights),
-        profits: Rc::new(profits),
+        weights: &weights,
+        profits: &profits,
     };
 
     let null_callback = |_: usize, _: &Node| {};
@@ -112,7 +111,7 @@ fn main() {
         null_callback,
     );
     if let Some((cost, node)) = result {
-        println!("Best profit: {}", u32::MAX - cost);
+        println!("Best profit: {cost}");
         println!("Items taken: {:?}", node.items);
     } else {
         println!("No solution found");
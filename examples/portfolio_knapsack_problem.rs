@@ -0,0 +1,135 @@
+use std::{sync::Arc, time::Duration};
+
+use tree_traversal::{
+    Maximize,
+    node::{LowerBound, Priority, TreeNode},
+    traversal::{BeamTraversal, BranchAndBoundTraversal, GreedyTraversal, Portfolio, TimeLimit},
+};
+
+#[derive(Clone)]
+struct Node {
+    items: Vec<bool>,
+    capacity: u32,
+    weights: Arc<[u32]>,
+    profits: Arc<[u32]>,
+}
+
+impl Node {
+    fn total_profit(&self) -> u32 {
+        self.items
+            .iter()
+            .copied()
+            .enumerate()
+            .map(|(i, b)| if b { self.profits[i] } else { 0 })
+            .sum()
+    }
+
+    fn max_profit(&self) -> u32 {
+        let current_profit = self.total_profit();
+        let max_remained_profit: u32 = self.profits[self.items.len()..].iter().sum();
+        current_profit + max_remained_profit
+    }
+}
+
+impl LowerBound for Node {
+    type Cost = Maximize<u32>;
+
+    fn cost_lb(&self) -> Option<Self::Cost> {
+        let max_profit = self.max_profit();
+        Some(Maximize(max_profit))
+    }
+}
+
+impl Priority for Node {
+    type Value = u32;
+
+    fn priority(&self) -> Option<Self::Value> {
+        Some(self.max_profit())
+    }
+}
+
+impl TreeNode for Node {
+    type Cost = Maximize<u32>;
+
+    fn is_leaf(&self) -> bool {
+        self.profits.len() == self.items.len()
+    }
+
+    fn generate_child_nodes(&self) -> Vec<Self> {
+        if self.is_leaf() {
+            return vec![];
+        }
+
+        let total_weight: u32 = self
+            .items
+            .iter()
+            .copied()
+            .enumerate()
+            .map(|(i, b)| if b { self.weights[i] } else { 0 })
+            .sum();
+
+        let mut children = vec![];
+
+        let next_idx = self.items.len();
+        if self.capacity >= total_weight + self.weights[next_idx] {
+            let mut c1 = self.items.clone();
+            c1.push(true);
+            children.push(Node {
+                items: c1,
+                capacity: self.capacity,
+                weights: self.weights.clone(),
+                profits: self.profits.clone(),
+            });
+        }
+
+        let mut c2 = self.items.clone();
+        c2.push(false);
+        children.push(Node {
+            items: c2,
+            capacity: self.capacity,
+            weights: self.weights.clone(),
+            profits: self.profits.clone(),
+        });
+
+        children
+    }
+
+    fn cost(&self) -> Option<Self::Cost> {
+        let profit = self.total_profit();
+        Some(Maximize(profit))
+    }
+}
+
+fn main() {
+    let weights = [2, 3, 5, 1, 4, 2, 6, 3, 4, 2, 5, 1];
+    let profits = [10, 12, 20, 3, 15, 7, 22, 9, 14, 6, 18, 2];
+    let capacity = 20;
+
+    let root_node = Node {
+        items: vec![],
+        capacity,
+        weights: Arc::new(weights),
+        profits: Arc::new(profits),
+    };
+
+    let portfolio = Portfolio::new()
+        .add("greedy", |root, _| GreedyTraversal::new(root))
+        .add("beam", |root, _| BeamTraversal::new(root, 2, 16))
+        .add_complete("branch-and-bound", BranchAndBoundTraversal::with_incumbent);
+
+    // the branch-and-bound proves optimality long before the time limit, which stops all strategies
+    let stop_condition = TimeLimit(Duration::from_secs(10));
+    let outcome = portfolio.run(root_node, stop_condition);
+    for (name, o) in &outcome.outcomes {
+        println!(
+            "{name}: {} nodes visited, stopped by {:?}",
+            o.n_visited, o.termination
+        );
+    }
+    if let (Some((cost, node)), Some(name)) = (outcome.best(), outcome.winner_name()) {
+        println!("Best profit: {cost} found by {name}");
+        println!("Items taken: {:?}", node.items);
+    } else {
+        println!("No solution found");
+    }
+}
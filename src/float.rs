@@ -0,0 +1,319 @@
+//! Defines a totally ordered floating-point number for real-valued costs and priorities, and the
+//! conversion of costs to `f64`.
+
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fmt,
+    hash::{Hash, Hasher},
+    iter::Sum,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// The error returned when creating a [`NotNan`] from NaN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FloatIsNan;
+
+impl fmt::Display for FloatIsNan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("NaN is not allowed")
+    }
+}
+
+impl Error for FloatIsNan {}
+
+/// An `f64` which is never NaN, and is therefore totally ordered.
+///
+/// `NotNan` implements `Ord`, so it can be used as the cost of a [`TreeNode`](crate::node::TreeNode),
+/// the lower bound of a [`LowerBound`](crate::node::LowerBound) or the value of a
+/// [`Priority`](crate::node::Priority) node, e.g. for distances or probabilities. It also implements
+/// `Default`, `Add`, `Sub` and [`ToF64`], which are required by some traversals.
+///
+/// Infinities are allowed. Arithmetic operations panic if their result is NaN, e.g.
+/// `NotNan::INFINITY - NotNan::INFINITY`, so a NaN cannot be introduced silently.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct NotNan(f64);
+
+impl NotNan {
+    /// Zero.
+    pub const ZERO: Self = Self(0.0);
+    /// Positive infinity, greater than any other value.
+    pub const INFINITY: Self = Self(f64::INFINITY);
+    /// Negative infinity, less than any other value.
+    pub const NEG_INFINITY: Self = Self(f64::NEG_INFINITY);
+
+    /// Creates a new `NotNan`, or returns an error if `value` is NaN.
+    pub fn new(value: f64) -> Result<Self, FloatIsNan> {
+        if value.is_nan() {
+            Err(FloatIsNan)
+        } else {
+            Ok(Self(value))
+        }
+    }
+
+    /// Returns the wrapped `f64`.
+    pub fn into_inner(self) -> f64 {
+        self.0
+    }
+
+    /// Returns the absolute value.
+    pub fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+
+    fn checked(value: f64) -> Self {
+        Self::new(value).expect("arithmetic operation on NotNan resulted in NaN")
+    }
+}
+
+impl Eq for NotNan {}
+
+impl PartialOrd for NotNan {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NotNan {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .partial_cmp(&other.0)
+            .expect("NotNan values are always comparable")
+    }
+}
+
+impl Hash for NotNan {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // 0.0 and -0.0 are equal, so they must have the same hash
+        let value = if self.0 == 0.0 { 0.0 } else { self.0 };
+        value.to_bits().hash(state);
+    }
+}
+
+impl fmt::Display for NotNan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl TryFrom<f64> for NotNan {
+    type Error = FloatIsNan;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<NotNan> for f64 {
+    fn from(value: NotNan) -> Self {
+        value.0
+    }
+}
+
+impl Neg for NotNan {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl Sum for NotNan {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+/// A cost which can be converted to `f64`, e.g. by MCTS to normalize leaf costs into rewards.
+///
+/// Unlike `Into<f64>`, it is implemented for every primitive integer type, including `u64`, `i64`
+/// and `usize`, whose values are rounded to the nearest `f64`. It is also implemented for `f32`,
+/// `f64` and [`NotNan`].
+pub trait ToF64 {
+    /// Converts the cost to `f64`.
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_to_f64 {
+    ($($t:ty),*) => {
+        $(
+            impl ToF64 for $t {
+                #[allow(clippy::cast_precision_loss, clippy::cast_lossless)]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_to_f64!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+impl ToF64 for NotNan {
+    fn to_f64(self) -> f64 {
+        self.0
+    }
+}
+
+macro_rules! impl_arithmetic {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt) => {
+        impl $trait for NotNan {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                Self::checked(self.0 $op rhs.0)
+            }
+        }
+
+        impl $trait<f64> for NotNan {
+            type Output = Self;
+
+            fn $method(self, rhs: f64) -> Self {
+                Self::checked(self.0 $op rhs)
+            }
+        }
+
+        impl $assign_trait for NotNan {
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+
+        impl $assign_trait<f64> for NotNan {
+            fn $assign_method(&mut self, rhs: f64) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+impl_arithmetic!(Add, add, AddAssign, add_assign, +);
+impl_arithmetic!(Sub, sub, SubAssign, sub_assign, -);
+impl_arithmetic!(Mul, mul, MulAssign, mul_assign, *);
+impl_arithmetic!(Div, div, DivAssign, div_assign, /);
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::NotNan;
+    use crate::traversal::functional::{
+        AbsoluteTolerance, BranchAndBoundContainer, Reachable, bbs_solve, bms, find_best,
+    };
+
+    #[derive(Debug, Clone, Copy)]
+    struct Node {
+        depth: usize,
+        weight: f64,
+        profit: f64,
+    }
+
+    #[test]
+    fn test_not_nan() {
+        assert!(NotNan::new(f64::NAN).is_err());
+        let mut values: Vec<NotNan> = [2.5, -1.0, f64::INFINITY, 0.0]
+            .into_iter()
+            .map(|v| NotNan::new(v).unwrap())
+            .collect();
+        values.sort();
+        assert_eq!(values[0], NotNan::new(-1.0).unwrap());
+        assert_eq!(values[3], NotNan::INFINITY);
+        let total: NotNan = values[..3].iter().copied().sum();
+        assert_eq!(total.into_inner(), 1.5);
+        assert!(std::panic::catch_unwind(|| NotNan::INFINITY - NotNan::INFINITY).is_err());
+
+        let weights = [2.5, 1.5, 3.0, 2.0];
+        let profits = [3.1, 1.9, 4.2, 2.4];
+        let capacity = 5.5;
+        let total_items = weights.len();
+
+        let successor_fn = |n: &Node| {
+            if n.depth == total_items {
+                return vec![];
+            }
+            let skip = Node {
+                depth: n.depth + 1,
+                ..*n
+            };
+            let take = Node {
+                depth: n.depth + 1,
+                weight: n.weight + weights[n.depth],
+                profit: n.profit + profits[n.depth],
+            };
+            [skip, take]
+                .into_iter()
+                .filter(|c| c.weight <= capacity)
+                .collect()
+        };
+        let leaf_check_fn = |n: &Node| n.depth == total_items;
+        // maximizing the profit is minimizing its opposite
+        let cost_fn = |n: &Node| NotNan::new(-n.profit).ok();
+        let lower_bound_fn = |n: &Node| {
+            let max_remained_profit: f64 = profits[n.depth..].iter().sum();
+            NotNan::new(-(n.profit + max_remained_profit)).ok()
+        };
+        let priority_fn = |n: &Node| NotNan::new(-n.profit).ok();
+        let root = Node {
+            depth: 0,
+            weight: 0.0,
+            profit: 0.0,
+        };
+
+        let result = bbs_solve(
+            root,
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            usize::MAX,
+            Duration::from_secs(10),
+        );
+        let (cost, _) = result.best.expect("BBS should find a valid solution");
+        assert!((cost.into_inner() + 7.3).abs() < 1e-9);
+        assert_eq!(result.relative_gap(), Some(0.0));
+
+        // with a tolerance, only nodes improving on the incumbent by more than it are expanded
+        let epsilon = 1.5;
+        let container = BranchAndBoundContainer::new(
+            root,
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+        )
+        .with_tolerance(AbsoluteTolerance(NotNan::new(epsilon).unwrap()));
+        let mut traversal = Reachable::new(container);
+        let (tolerant_cost, _) = find_best(
+            &mut traversal,
+            leaf_check_fn,
+            cost_fn,
+            usize::MAX,
+            Duration::from_secs(10),
+            |_, _| {},
+        )
+        .expect("BBS should find a valid solution");
+        assert!(tolerant_cost <= cost + epsilon);
+        // exhausting the search only proves that the best leaf is within the tolerance, here
+        // because a node whose lower bound is better than the optimum by less than it was pruned
+        let container = traversal.container();
+        assert!(container.is_exhausted());
+        let lb = container.lower_bound().expect("a leaf has been found");
+        assert!(lb < tolerant_cost && lb >= tolerant_cost - epsilon);
+
+        let (beam_cost, _) = bms(
+            root,
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            priority_fn,
+            2,
+            4,
+            usize::MAX,
+            Duration::from_secs(10),
+        )
+        .expect("beam search should find a valid solution");
+        assert!(beam_cost >= cost);
+    }
+}
@@ -3,6 +3,11 @@
 #![allow(clippy::module_name_repetitions)]
 #![doc = include_str!("../README.md")]
 
+mod float;
 pub mod node;
+mod objective;
 pub mod traversal;
 pub(crate) mod utils;
+
+pub use float::{FloatIsNan, NotNan, ToF64};
+pub use objective::Maximize;
@@ -1,5 +1,11 @@
 //! Defines the TreeNode trait for tree traversal nodes.
 
+use std::{hash::Hash, ops::Add};
+
+mod traced;
+
+pub use traced::Traced;
+
 /// TreeNode trait that implements basic functionalities for tree traversal nodes.
 pub trait TreeNode: Sized {
     /// The type representing the cost associated with the node.
@@ -14,7 +20,22 @@ pub trait TreeNode: Sized {
     fn cost(&self) -> Option<Self::Cost>;
 }
 
+/// Trait defining lazy child generation for tree nodes.
+/// This is useful for depth-first and branch-and-bound traversals, which then only generate the
+/// children they are about to visit instead of all of them at once.
+pub trait LazyChildren: TreeNode {
+    /// The type of the iterator over the child nodes.
+    type Children: Iterator<Item = Self>;
+
+    /// Returns an iterator generating the child nodes of the current node one at a time, in the
+    /// order they should be visited.
+    fn lazy_child_nodes(&self) -> Self::Children;
+}
+
 /// Trait defining the lower bound functionality for tree nodes.
+///
+/// The lower bound is an optimistic bound: no leaf below the node has a lower cost. When the cost is
+/// [`Maximize`](crate::Maximize), it is therefore an upper bound on the objective to maximize.
 pub trait LowerBound {
     /// The type representing the cost associated with the node.
     type Cost: Copy + Ord;
@@ -24,6 +45,21 @@ pub trait LowerBound {
     fn cost_lb(&self) -> Option<Self::Cost>;
 }
 
+/// Trait defining the heuristic functionality for tree nodes.
+/// This is useful for A* search, where nodes are ordered by `path_cost() + heuristic()`.
+pub trait Heuristic {
+    /// The type representing the cost associated with the node.
+    type Cost: Copy + Ord + Add<Output = Self::Cost>;
+
+    /// Returns the cost of the path from the root to the node, i.e. `g(n)`.
+    /// For leaf nodes this is expected to be the final cost of the node.
+    fn path_cost(&self) -> Self::Cost;
+    /// Returns an estimate of the remaining cost from the node to its best leaf, i.e. `h(n)`.
+    /// The estimate must never overestimate the true remaining cost and must be zero for leaf nodes.
+    /// If the current node does not satisfy problem constraints, returns None.
+    fn heuristic(&self) -> Option<Self::Cost>;
+}
+
 /// Trait defining the priority functionality for tree nodes.
 /// This is useful for greedy or best-first search algorithms.
 pub trait Priority {
@@ -35,3 +71,61 @@ pub trait Priority {
     /// If the current node does not satisfy problem constraints, returns None.
     fn priority(&self) -> Option<Self::Value>;
 }
+
+/// Trait defining the state key functionality for tree nodes.
+/// This is useful when different paths of the tree lead to the same state, so that already seen
+/// states can be detected and skipped instead of being expanded again.
+pub trait StateKey {
+    /// The type representing the key of the state of the node.
+    type Key: Eq + Hash;
+
+    /// Returns the key of the state of the node.
+    /// Two nodes with the same key are regarded as the same state, so the key should also
+    /// capture whatever determines the costs of the leaves below the node.
+    fn state_key(&self) -> Self::Key;
+}
+
+/// Trait defining the dominance functionality for tree nodes.
+/// This is useful for branch and bound search, where a node can be discarded when another node
+/// with the same remaining subproblem is known to be at least as good.
+pub trait Dominance {
+    /// The type representing the remaining subproblem of the node.
+    /// Only nodes with the same key are compared with each other.
+    type Key: Eq + Hash;
+    /// The type representing the values used to compare nodes with the same key.
+    type Label;
+
+    /// Returns the key of the remaining subproblem of the node.
+    fn dominance_key(&self) -> Self::Key;
+    /// Returns the label of the node, e.g. its resource usage and its cost so far.
+    fn label(&self) -> Self::Label;
+    /// Returns `true` if a node with label `a` is at least as good as a node with label `b`
+    /// that has the same key, i.e. the best leaf below the latter cannot be better.
+    fn dominates(a: &Self::Label, b: &Self::Label) -> bool;
+}
+
+/// IncrementalNode trait for search states modified in place by applying and undoing moves.
+/// This is an alternative to [`TreeNode`] for large states, whose children are costly to generate
+/// as full copies: the traversal moves a single state down the tree and back up, and only clones
+/// it when a new best leaf is found.
+pub trait IncrementalNode: Sized {
+    /// The type representing a move from a node to one of its children.
+    type Move;
+    /// The type of the moves from a node, e.g. a `Vec` or a lazy iterator. It does not borrow the
+    /// node, which is modified while its moves are explored.
+    type Moves: IntoIterator<Item = Self::Move>;
+    /// The type representing the cost associated with the node.
+    type Cost: Copy + Ord;
+
+    /// Checks if the node is a leaf node.
+    fn is_leaf(&self) -> bool;
+    /// Returns the moves from the current node to its children, best ranked first.
+    fn moves(&self) -> Self::Moves;
+    /// Applies a move, turning the node into the corresponding child.
+    fn apply(&mut self, m: &Self::Move);
+    /// Reverts a move applied by [`apply`](Self::apply), turning the child back into its parent.
+    fn undo(&mut self, m: &Self::Move);
+    /// Returns the cost associated with the node, if any.
+    /// If the current node does not satisfy problem constraints, returns None.
+    fn cost(&self) -> Option<Self::Cost>;
+}
@@ -0,0 +1,275 @@
+use std::{fmt, ops::Deref, sync::Arc};
+
+use super::{Dominance, Heuristic, LowerBound, Priority, StateKey, TreeNode};
+
+struct Link<N> {
+    node: N,
+    parent: Option<Traced<N>>,
+    depth: usize,
+}
+
+impl<N> Drop for Link<N> {
+    fn drop(&mut self) {
+        // unlink the ancestors one by one instead of recursively, so that dropping the last node
+        // of a deep path does not overflow the stack
+        let mut parent = self.parent.take();
+        while let Some(p) = parent {
+            parent = Arc::into_inner(p.link).and_then(|mut link| link.parent.take());
+        }
+    }
+}
+
+/// A node wrapper keeping a link to its parent, so that the path from the root to any node can be
+/// reconstructed without each node storing a copy of its history.
+///
+/// The nodes of a path share their common ancestors, which are freed once no node below them is
+/// alive anymore. Cloning a `Traced` is cheap, and it dereferences to the wrapped node.
+///
+/// In the OOP API, `Traced<N>` implements the node traits implemented by `N`, so it can be used as
+/// the root node of any traversal: `Traced::new(root)`. In the functional API, the successor
+/// function wraps the children with [`children`](Self::children), e.g.
+/// `|n: &Traced<N>| n.children(successor_fn(n))`.
+pub struct Traced<N> {
+    link: Arc<Link<N>>,
+}
+
+impl<N> Clone for Traced<N> {
+    fn clone(&self) -> Self {
+        Self {
+            link: Arc::clone(&self.link),
+        }
+    }
+}
+
+impl<N: fmt::Debug> fmt::Debug for Traced<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Traced")
+            .field("node", &self.link.node)
+            .field("depth", &self.link.depth)
+            .finish()
+    }
+}
+
+impl<N> Deref for Traced<N> {
+    type Target = N;
+
+    fn deref(&self) -> &N {
+        &self.link.node
+    }
+}
+
+impl<N> Traced<N> {
+    /// Creates a new `Traced` root node, without any parent.
+    pub fn new(root_node: N) -> Self {
+        Self {
+            link: Arc::new(Link {
+                node: root_node,
+                parent: None,
+                depth: 0,
+            }),
+        }
+    }
+
+    /// Wraps a child of this node.
+    pub fn child(&self, node: N) -> Self {
+        Self {
+            link: Arc::new(Link {
+                node,
+                parent: Some(self.clone()),
+                depth: self.link.depth + 1,
+            }),
+        }
+    }
+
+    /// Wraps the children of this node.
+    pub fn children(&self, nodes: impl IntoIterator<Item = N>) -> Vec<Self> {
+        nodes.into_iter().map(|n| self.child(n)).collect()
+    }
+
+    /// Returns the wrapped node.
+    pub fn node(&self) -> &N {
+        &self.link.node
+    }
+
+    /// Returns the parent of this node, or `None` for the root node.
+    pub fn parent(&self) -> Option<&Self> {
+        self.link.parent.as_ref()
+    }
+
+    /// Returns the depth of this node, i.e. the number of its ancestors.
+    pub fn depth(&self) -> usize {
+        self.link.depth
+    }
+
+    /// Returns the nodes of the path from the root node to this node, both included.
+    pub fn path(&self) -> Vec<&N> {
+        let mut path = Vec::with_capacity(self.depth() + 1);
+        let mut current = Some(self);
+        while let Some(traced) = current {
+            path.push(traced.node());
+            current = traced.parent();
+        }
+        path.reverse();
+        path
+    }
+}
+
+impl<N: TreeNode> TreeNode for Traced<N> {
+    type Cost = N::Cost;
+
+    fn is_leaf(&self) -> bool {
+        self.node().is_leaf()
+    }
+
+    fn generate_child_nodes(&self) -> Vec<Self> {
+        self.children(self.node().generate_child_nodes())
+    }
+
+    fn cost(&self) -> Option<Self::Cost> {
+        self.node().cost()
+    }
+}
+
+impl<N: LowerBound> LowerBound for Traced<N> {
+    type Cost = N::Cost;
+
+    fn cost_lb(&self) -> Option<Self::Cost> {
+        self.node().cost_lb()
+    }
+}
+
+impl<N: Heuristic> Heuristic for Traced<N> {
+    type Cost = N::Cost;
+
+    fn path_cost(&self) -> Self::Cost {
+        self.node().path_cost()
+    }
+
+    fn heuristic(&self) -> Option<Self::Cost> {
+        self.node().heuristic()
+    }
+}
+
+impl<N: Priority> Priority for Traced<N> {
+    type Value = N::Value;
+
+    fn priority(&self) -> Option<Self::Value> {
+        self.node().priority()
+    }
+}
+
+impl<N: StateKey> StateKey for Traced<N> {
+    type Key = N::Key;
+
+    fn state_key(&self) -> Self::Key {
+        self.node().state_key()
+    }
+}
+
+impl<N: Dominance> Dominance for Traced<N> {
+    type Key = N::Key;
+    type Label = N::Label;
+
+    fn dominance_key(&self) -> Self::Key {
+        self.node().dominance_key()
+    }
+
+    fn label(&self) -> Self::Label {
+        self.node().label()
+    }
+
+    fn dominates(a: &Self::Label, b: &Self::Label) -> bool {
+        N::dominates(a, b)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::Traced;
+    use crate::traversal::functional::bbs;
+
+    /// A knapsack node storing only the last decision instead of all the items taken so far.
+    #[derive(Debug, Clone, Copy)]
+    struct Node {
+        depth: usize,
+        taken: bool,
+        weight: u32,
+        profit: u32,
+    }
+
+    #[test]
+    fn test_traced() {
+        let weights = [2, 3, 5, 1, 4, 2];
+        let profits = [10, 12, 20, 3, 15, 7];
+        let capacity = 9;
+        let total_items = weights.len();
+
+        let successor_fn = |n: &Traced<Node>| {
+            if n.depth == total_items {
+                return vec![];
+            }
+            let skip = Node {
+                depth: n.depth + 1,
+                taken: false,
+                ..**n
+            };
+            let take = Node {
+                depth: n.depth + 1,
+                taken: true,
+                weight: n.weight + weights[n.depth],
+                profit: n.profit + profits[n.depth],
+            };
+            let children = [take, skip].into_iter().filter(|c| c.weight <= capacity);
+            n.children(children)
+        };
+        let lower_bound_fn = |n: &Traced<Node>| {
+            let max_remained_profit: u32 = profits[n.depth..].iter().sum();
+            Some(u32::MAX - (n.profit + max_remained_profit))
+        };
+        let cost_fn = |n: &Traced<Node>| Some(u32::MAX - n.profit);
+        let leaf_check_fn = |n: &Traced<Node>| n.depth == total_items;
+
+        let root = Node {
+            depth: 0,
+            taken: false,
+            weight: 0,
+            profit: 0,
+        };
+        let (cost, best) = bbs(
+            Traced::new(root),
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            usize::MAX,
+            Duration::from_secs(10),
+        )
+        .expect("BBS should find a valid solution");
+        assert_eq!(u32::MAX - cost, 37);
+        assert_eq!(best.depth(), total_items);
+
+        let path = best.path();
+        assert_eq!(path.len(), total_items + 1);
+        let taken: Vec<_> = path[1..].iter().map(|n| n.taken).collect();
+        let profit: u32 = (0..total_items)
+            .filter(|&i| taken[i])
+            .map(|i| profits[i])
+            .sum();
+        assert_eq!(profit, 37);
+        let weight: u32 = (0..total_items)
+            .filter(|&i| taken[i])
+            .map(|i| weights[i])
+            .sum();
+        assert!(weight <= capacity);
+
+        // dropping a deep path does not overflow the stack
+        let mut deep = Traced::new(0);
+        for i in 1..1_000_000 {
+            deep = deep.child(i);
+        }
+        assert_eq!(deep.path().len(), 1_000_000);
+        drop(deep);
+    }
+}
@@ -0,0 +1,250 @@
+//! Defines the cost wrapper used to maximize an objective instead of minimizing a cost.
+
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Sub},
+};
+
+use crate::ToF64;
+
+/// A cost wrapper turning the minimization performed by the traversals into a maximization of the
+/// wrapped objective, e.g. the profit of a knapsack.
+///
+/// `Maximize` is ordered in reverse, like [`Reverse`](std::cmp::Reverse): a greater objective is a
+/// lower, i.e. better, cost. Using `Maximize<C>` as the cost type sets the objective sense of the
+/// whole search, without rewriting the objective as e.g. `u32::MAX - profit`:
+/// - [`traverse`](crate::traversal::traverse) and [`find_best`](crate::traversal::find_best) keep
+///   the leaves with the greatest objective, sorted by decreasing objective
+/// - the lower bound of a [`LowerBound`](crate::node::LowerBound) node is an optimistic bound, so
+///   `Maximize(upper_bound)` where `upper_bound` is an upper bound on the objective of the leaves
+///   below the node, and branch-and-bound prunes the nodes whose upper bound is not greater than
+///   the best objective found so far
+/// - stop conditions compare costs the same way, e.g. `TargetCost(Maximize(100))` stops once a
+///   leaf with an objective of at least 100 is found
+/// - [`ToF64`] converts `Maximize(x)` to `-x`, so MCTS and the relative optimality gap see the
+///   same order as the other traversals
+/// - adding two costs adds the objectives, so A* accumulates the objective along the path, and
+///   the heuristic of a [`Heuristic`](crate::node::Heuristic) node is `Maximize(estimate)` where
+///   `estimate` is an upper bound on the objective still to be gained below the node
+/// - subtracting two costs gives the difference of the minimized costs as a plain objective, so
+///   the absolute optimality gap is `upper bound - best objective`, and the epsilon of an
+///   [`AbsoluteTolerance`](crate::traversal::functional::AbsoluteTolerance) is a plain objective
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Maximize<C>(pub C);
+
+impl<C> Maximize<C> {
+    /// Returns the wrapped objective.
+    pub fn into_inner(self) -> C {
+        self.0
+    }
+}
+
+impl<C: Ord> PartialOrd for Maximize<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> Ord for Maximize<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+impl<C: Add<Output = C>> Add for Maximize<C> {
+    type Output = Self;
+
+    /// Returns the cost of the sum of the objectives, e.g. the path objective plus an estimate of
+    /// the remaining objective.
+    fn add(self, rhs: Self) -> Self {
+        Maximize(self.0 + rhs.0)
+    }
+}
+
+impl<C: Sub<Output = C>> Sub for Maximize<C> {
+    type Output = C;
+
+    /// Returns the difference of the minimized costs, `rhs.0 - self.0`, e.g. how much more
+    /// objective `rhs` has than `self`.
+    fn sub(self, rhs: Self) -> C {
+        rhs.0 - self.0
+    }
+}
+
+impl<C: ToF64> ToF64 for Maximize<C> {
+    fn to_f64(self) -> f64 {
+        -self.0.to_f64()
+    }
+}
+
+impl<C: fmt::Display> fmt::Display for Maximize<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::Maximize;
+    use crate::traversal::functional::{
+        AbsoluteTolerance, Any, BranchAndBoundContainer, MaxOps, Reachable, TargetCost,
+        TerminationReason, astar, bbs, bbs_reach, bbs_solve, find_best, mcts, traverse,
+        traverse_until,
+    };
+
+    type Node = Vec<bool>;
+
+    #[test]
+    fn test_maximize() {
+        let weights = [4, 2, 6, 3, 4];
+        let profits = [100, 20, 2, 5, 10];
+        let capacity = 8;
+        let total_items = weights.len();
+
+        let total = |n: &Node, values: &[u32]| -> u32 {
+            n.iter()
+                .zip(values)
+                .filter(|(b, _)| **b)
+                .map(|(_, v)| v)
+                .sum()
+        };
+        let successor_fn = |n: &Node| {
+            if n.len() == total_items {
+                return vec![];
+            }
+            let mut children = vec![];
+            if total(n, &weights) + weights[n.len()] <= capacity {
+                let mut c = n.clone();
+                c.push(true);
+                children.push(c);
+            }
+            let mut c = n.clone();
+            c.push(false);
+            children.push(c);
+            children
+        };
+        let leaf_check_fn = |n: &Node| n.len() == total_items;
+        let cost_fn = |n: &Node| Some(Maximize(total(n, &profits)));
+        // an optimistic bound on the profit, i.e. an upper bound
+        let lower_bound_fn = |n: &Node| {
+            let max_remained_profit: u32 = profits[n.len()..].iter().sum();
+            Some(Maximize(total(n, &profits) + max_remained_profit))
+        };
+
+        let (cost, best) = bbs(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            usize::MAX,
+            Duration::from_secs(10),
+        )
+        .expect("BBS should find a valid solution");
+        assert_eq!(cost, Maximize(120));
+        assert_eq!(best, vec![true, true, false, false, false]);
+
+        // leaves are sorted by decreasing profit
+        let mut tree = bbs_reach(vec![], successor_fn, leaf_check_fn, cost_fn, |_: &Node| {
+            Some(Maximize(u32::MAX))
+        });
+        let solutions = traverse(
+            &mut tree,
+            leaf_check_fn,
+            cost_fn,
+            usize::MAX,
+            Duration::from_secs(10),
+            3,
+            |_, _| {},
+        );
+        let best_profits: Vec<_> = solutions.iter().map(|(c, _)| c.into_inner()).collect();
+        assert_eq!(best_profits, vec![120, 110, 105]);
+
+        // the target is reached by any leaf with at least the target profit
+        let mut tree = bbs_reach(vec![], successor_fn, leaf_check_fn, cost_fn, lower_bound_fn);
+        let outcome = traverse_until(
+            &mut tree,
+            leaf_check_fn,
+            cost_fn,
+            1,
+            Any(MaxOps(usize::MAX), TargetCost(Maximize(100))),
+            |_, _| {},
+        );
+        assert_eq!(outcome.termination, TerminationReason::TargetCost);
+        assert!(outcome.best().is_some_and(|(c, _)| c.0 >= 100));
+
+        // the gaps are measured in objective units
+        let res = bbs_solve(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            10,
+            Duration::from_secs(10),
+        );
+        assert_eq!(res.best_cost(), Some(Maximize(15)));
+        assert_eq!(res.lower_bound, Some(Maximize(137)));
+        assert_eq!(res.absolute_gap(), Some(122));
+        assert_eq!(res.relative_gap(), Some(122.0 / 15.0));
+
+        let (cost, _) = mcts(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            2f64.sqrt(),
+            42,
+            usize::MAX,
+            Duration::from_secs(10),
+        )
+        .expect("MCTS should find a valid solution");
+        assert_eq!(cost, Maximize(120));
+
+        // the heuristic is an upper bound on the profit of the remaining items
+        let (cost, best, proven) = astar(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            |n: &Node| Maximize(total(n, &profits)),
+            |n: &Node| Some(Maximize(profits[n.len()..].iter().sum())),
+            usize::MAX,
+            Duration::from_secs(10),
+        )
+        .expect("A* should find a valid solution");
+        assert_eq!(cost, Maximize(120));
+        assert_eq!(best, vec![true, true, false, false, false]);
+        assert!(proven);
+
+        // the tolerance is a profit, and the best leaf is proven within it of the optimum
+        let epsilon = 100;
+        let container = BranchAndBoundContainer::new(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+        )
+        .with_tolerance(AbsoluteTolerance(epsilon));
+        let mut tree = Reachable::new(container);
+        let (cost, _) = find_best(
+            &mut tree,
+            leaf_check_fn,
+            cost_fn,
+            usize::MAX,
+            Duration::from_secs(10),
+            |_, _| {},
+        )
+        .expect("BBS should find a valid solution");
+        assert!(cost.0 < 120 && cost.0 + epsilon >= 120);
+        let container = tree.container();
+        assert!(container.is_exhausted());
+        let lb = container
+            .lower_bound()
+            .expect("a lower bound should be known");
+        assert!(lb < cost && cost - lb <= epsilon);
+    }
+}
@@ -1,25 +1,63 @@
 //! Tree traversal algorithms and iterator-based traversal adapters.
 //!
-//! This module provides several traversal implementations (BFS, DFS, Beam, Greedy,
-//! Branch-and-Bound, Priority-First) as both iterator-based adapters and functional
-//! helpers under the `functional` submodule. The implementations are generic over a
-//! `TreeNode` trait so they can be reused for different problem domains (e.g., knapsack,
-//! TSP).
+//! This module provides several traversal implementations (BFS, DFS, Beam, Anytime Beam,
+//! Beam Stack, Greedy, Branch-and-Bound, Best-First Branch-and-Bound, Parallel Branch-and-Bound,
+//! Pareto Branch-and-Bound, Priority-First, A*, Iterative Deepening, Limited Discrepancy,
+//! Monte Carlo Tree Search) as both iterator-based adapters and functional helpers under the
+//! `functional` submodule.
+//! The implementations are generic over a `TreeNode` trait so they can be reused for
+//! different problem domains (e.g., knapsack, TSP).
 
+mod abms;
+mod astar;
 mod bbs;
+mod bfbb;
 mod bfs;
 mod bms;
+mod bss;
 mod common;
 mod dfs;
 mod gds;
+mod idastar;
+mod iddfs;
+mod incremental;
+mod lds;
+mod mcts;
+mod pareto;
+mod pbbs;
 mod pfs;
+mod portfolio;
 
 pub mod functional;
 
+pub use abms::AnytimeBeamTraversal;
+pub use astar::AStarTraversal;
 pub use bbs::BranchAndBoundTraversal;
+pub use bfbb::BestFirstBranchAndBoundTraversal;
 pub use bfs::BreadthFirstTraversal;
 pub use bms::BeamTraversal;
-pub use common::{Traversal, find_best, traverse};
+pub use bss::BeamStackTraversal;
+pub use common::{
+    Traversal, find_best, find_best_observed, find_best_until, find_best_with_outcome,
+    improving_leaves, traverse, traverse_observed, traverse_pareto, traverse_until,
+    traverse_with_outcome,
+};
 pub use dfs::DepthFirstTraversal;
+pub use functional::{
+    All, Any, Cancel, ExpansionObserver, Frontier, Improvement, ImprovingLeaves, Incumbent,
+    MaxFrontier, MaxOps, MaxSolutions, Objectives, ParetoArchive, PruneReason, SearchObserver,
+    SearchOutcome, SearchProgress, SharedIncumbent, Stagnation, StopCondition, TargetCost,
+    TerminationReason, TimeLimit,
+};
 pub use gds::GreedyTraversal;
+pub use idastar::IterativeDeepeningAStarTraversal;
+pub use iddfs::IterativeDeepeningTraversal;
+pub use incremental::{
+    incremental_branch_and_bound, incremental_depth_first, incremental_limited_discrepancy,
+};
+pub use lds::{DepthBoundedDiscrepancyTraversal, LimitedDiscrepancyTraversal};
+pub use mcts::MonteCarloTraversal;
+pub use pareto::ParetoBranchAndBoundTraversal;
+pub use pbbs::parallel_branch_and_bound;
 pub use pfs::PriorityFirstTraversal;
+pub use portfolio::{Portfolio, PortfolioOutcome};
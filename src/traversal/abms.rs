@@ -0,0 +1,75 @@
+use std::{iter::FusedIterator, marker::PhantomData};
+
+use crate::node::{Priority, TreeNode};
+
+use super::functional::{AnytimeBeamContainer, Frontier, Reachable, abms_reach};
+
+type AnytimeBeamState<N, P, FW> =
+    Reachable<AnytimeBeamContainer<N, fn(&N) -> Vec<N>, fn(&N) -> Option<P>, P, FW>>;
+
+/// Anytime Beam traversal implementation.
+pub struct AnytimeBeamTraversal<
+    N: Priority,
+    S = AnytimeBeamState<N, <N as Priority>::Value, fn(usize) -> usize>,
+> {
+    state: S,
+    _node: PhantomData<fn() -> N>,
+}
+
+impl<C, N, FW> AnytimeBeamTraversal<N, AnytimeBeamState<N, N::Value, FW>>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + Priority + Clone,
+    FW: Fn(usize) -> usize,
+{
+    /// Creates a new `AnytimeBeamTraversal` instance that performs an anytime beam search starting from the given
+    /// root node.
+    ///
+    /// Anytime beam search runs a beam search and restarts it from the root with a wider beam each time the beam
+    /// empties, yielding a first solution quickly and then better ones as the beam grows.
+    ///
+    /// # Parameters
+    /// - `root_node`: The starting node for the traversal.
+    /// - `branch_factor`: The maximum number of child nodes to consider per parent node.
+    /// - `beam_width`: The maximum number of nodes to keep in the beam at each level in the first run.
+    /// - `width_growth_fn`: A function that returns the beam width of the next run given the current one.
+    ///
+    /// # Returns
+    /// A new `AnytimeBeamTraversal` iterator.
+    pub fn new(root_node: N, branch_factor: usize, beam_width: usize, width_growth_fn: FW) -> Self {
+        let successor_fn: fn(&N) -> Vec<N> = |n| n.generate_child_nodes();
+        let priority_fn: fn(&N) -> Option<N::Value> = |n| n.priority();
+        let state = abms_reach(
+            root_node,
+            successor_fn,
+            priority_fn,
+            branch_factor,
+            beam_width,
+            width_growth_fn,
+        );
+        Self {
+            state,
+            _node: PhantomData,
+        }
+    }
+}
+
+impl<N: Priority, S: Iterator<Item = N>> Iterator for AnytimeBeamTraversal<N, S> {
+    type Item = N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.state.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint()
+    }
+}
+
+impl<N: Priority, S: FusedIterator<Item = N>> FusedIterator for AnytimeBeamTraversal<N, S> {}
+
+impl<N: Priority, S: Frontier> Frontier for AnytimeBeamTraversal<N, S> {
+    fn frontier_size(&self) -> Option<usize> {
+        self.state.frontier_size()
+    }
+}
@@ -0,0 +1,85 @@
+use std::{iter::FusedIterator, ops::Add};
+
+use crate::node::{Heuristic, TreeNode};
+
+use super::functional::{AStarContainer, Frontier, Reachable, astar_reach};
+
+type AStarState<C, N> = Reachable<
+    AStarContainer<C, N, fn(&N) -> Vec<N>, fn(&N) -> bool, fn(&N) -> C, fn(&N) -> Option<C>>,
+>;
+
+/// A* traversal implementation.
+pub struct AStarTraversal<N: Heuristic> {
+    state: AStarState<N::Cost, N>,
+}
+
+impl<C, N> AStarTraversal<N>
+where
+    C: Copy + Ord + Add<Output = C>,
+    N: TreeNode<Cost = C> + Heuristic<Cost = C>,
+{
+    /// Creates a new `AStarTraversal` instance that performs an A* search starting from the given root node.
+    ///
+    /// A* search always expands the node with the lowest `path_cost + heuristic`. With an admissible heuristic
+    /// the first leaf it reaches is optimal, so the traversal ends right after yielding that leaf.
+    /// The `cost` of a leaf node is expected to be equal to its `path_cost`.
+    ///
+    /// # Parameters
+    /// - `root_node`: The starting node for the traversal.
+    ///
+    /// # Returns
+    /// A new `AStarTraversal` iterator.
+    pub fn new(root_node: N) -> Self {
+        let successor_fn: fn(&N) -> Vec<N> = |n| n.generate_child_nodes();
+        let leaf_check_fn: fn(&N) -> bool = |n| n.is_leaf();
+        let path_cost_fn: fn(&N) -> C = |n| n.path_cost();
+        let heuristic_fn: fn(&N) -> Option<C> = |n| n.heuristic();
+        let state = astar_reach(
+            root_node,
+            successor_fn,
+            leaf_check_fn,
+            path_cost_fn,
+            heuristic_fn,
+        );
+        Self { state }
+    }
+
+    /// Returns `true` if the traversal has reached a leaf node, i.e. the optimal leaf has been
+    /// yielded and optimality is proven (given an admissible heuristic).
+    pub fn is_goal_reached(&self) -> bool {
+        self.state.container().is_goal_reached()
+    }
+}
+
+impl<C, N> Iterator for AStarTraversal<N>
+where
+    C: Copy + Ord + Add<Output = C>,
+    N: TreeNode<Cost = C> + Heuristic<Cost = C>,
+{
+    type Item = N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.state.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint()
+    }
+}
+
+impl<C, N> FusedIterator for AStarTraversal<N>
+where
+    C: Copy + Ord + Add<Output = C>,
+    N: TreeNode<Cost = C> + Heuristic<Cost = C>,
+{
+}
+
+impl<C, N> Frontier for AStarTraversal<N>
+where
+    C: Copy + Ord + Add<Output = C>,
+    N: TreeNode<Cost = C> + Heuristic<Cost = C>,
+{
+    fn frontier_size(&self) -> Option<usize> {
+        self.state.frontier_size()
+    }
+}
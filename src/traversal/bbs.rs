@@ -1,18 +1,85 @@
-use std::iter::FusedIterator;
+use std::{iter::FusedIterator, marker::PhantomData, ops::Sub};
 
-use crate::node::{LowerBound, TreeNode};
+use crate::node::{Dominance, LazyChildren, LowerBound, StateKey, TreeNode};
 
-use super::functional::bbs_reach;
+use super::functional::{
+    AbsoluteTolerance, BranchAndBoundContainer, ClosedSetContainer, DominanceStore, Frontier,
+    Incumbent, LazyBranchAndBoundContainer, Reachable, bbs_reach, dedup_reach,
+    lazy_bbs_ordered_reach, lazy_bbs_reach,
+};
+
+type BranchAndBoundFns<C, N> = BranchAndBoundContainer<
+    C,
+    N,
+    fn(&N) -> Vec<N>,
+    fn(&N) -> bool,
+    fn(&N) -> Option<C>,
+    fn(&N) -> Option<C>,
+>;
+
+type BranchAndBoundState<C, N, D, B, T> = Reachable<
+    BranchAndBoundContainer<
+        C,
+        N,
+        fn(&N) -> Vec<N>,
+        fn(&N) -> bool,
+        fn(&N) -> Option<C>,
+        fn(&N) -> Option<C>,
+        D,
+        (),
+        B,
+        T,
+    >,
+>;
+
+type BranchAndBoundClosedSetState<C, N, K> =
+    Reachable<ClosedSetContainer<BranchAndBoundFns<C, N>, K, (), fn(&N) -> K, fn(&N)>>;
+
+type DominanceStoreOf<N, K, L> = DominanceStore<K, L, fn(&N) -> K, fn(&N) -> L, fn(&L, &L) -> bool>;
+
+type LazyBranchAndBoundState<C, N, I> = Reachable<
+    LazyBranchAndBoundContainer<
+        C,
+        N,
+        fn(&N) -> I,
+        fn(&N) -> bool,
+        fn(&N) -> Option<C>,
+        fn(&N) -> Option<C>,
+        I,
+    >,
+>;
 
 /// Branch-and-Bound traversal implementation.
-pub struct BranchAndBoundTraversal<N> {
-    state: Box<dyn FusedIterator<Item = N>>,
+pub struct BranchAndBoundTraversal<
+    N: TreeNode,
+    S = BranchAndBoundState<<N as TreeNode>::Cost, N, (), Option<<N as TreeNode>::Cost>, ()>,
+> {
+    state: S,
+    _node: PhantomData<fn() -> N>,
+}
+
+fn bbs_state<C, N>(root_node: N) -> Reachable<BranchAndBoundFns<C, N>>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + LowerBound<Cost = C>,
+{
+    let successor_fn: fn(&N) -> Vec<N> = |n| n.generate_child_nodes();
+    let leaf_check_fn: fn(&N) -> bool = |n| n.is_leaf();
+    let cost_fn: fn(&N) -> Option<C> = |n| n.cost();
+    let lower_bound_fn: fn(&N) -> Option<C> = |n| n.cost_lb();
+    bbs_reach(
+        root_node,
+        successor_fn,
+        leaf_check_fn,
+        cost_fn,
+        lower_bound_fn,
+    )
 }
 
 impl<N, C> BranchAndBoundTraversal<N>
 where
-    C: Copy + Ord + 'static,
-    N: TreeNode<Cost = C> + LowerBound<Cost = C> + 'static,
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + LowerBound<Cost = C>,
 {
     /// Creates a new `BranchAndBoundTraversal` instance that performs a branch-and-bound search starting from the given root node.
     ///
@@ -25,25 +92,236 @@ where
     /// # Returns
     /// A new `BranchAndBoundTraversal` iterator.
     pub fn new(root_node: N) -> Self {
-        let state = bbs_reach(
+        Self {
+            state: bbs_state(root_node),
+            _node: PhantomData,
+        }
+    }
+
+    /// Creates a new `BranchAndBoundTraversal` instance like [`new`](BranchAndBoundTraversal::new), but seeded with an upper bound.
+    ///
+    /// Only nodes whose lower bound is better than `upper_bound`, e.g. the cost of a heuristic solution, are
+    /// expanded. Leaves are not compared with `upper_bound`, so the traversal may still yield leaves which are not
+    /// better than it.
+    ///
+    /// # Parameters
+    /// - `root_node`: The starting node for the traversal.
+    /// - `upper_bound`: The cost that leaves have to beat.
+    ///
+    /// # Returns
+    /// A new `BranchAndBoundTraversal` iterator.
+    pub fn with_upper_bound(root_node: N, upper_bound: C) -> Self {
+        Self::with_incumbent(root_node, Some(upper_bound))
+    }
+}
+
+impl<N, C, B> BranchAndBoundTraversal<N, BranchAndBoundState<C, N, (), B, ()>>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + LowerBound<Cost = C>,
+    B: Incumbent<C>,
+{
+    /// Creates a new `BranchAndBoundTraversal` instance like [`new`](BranchAndBoundTraversal::new), but reading and updating the given
+    /// incumbent.
+    ///
+    /// The cost of the best leaf found so far is kept in `incumbent`, so that a
+    /// [`SharedIncumbent`](super::functional::SharedIncumbent) lets several traversals prune with the best leaf found
+    /// by any of them.
+    ///
+    /// # Parameters
+    /// - `root_node`: The starting node for the traversal.
+    /// - `incumbent`: The incumbent read before expanding a node and offered the cost of every leaf.
+    ///
+    /// # Returns
+    /// A new `BranchAndBoundTraversal` iterator.
+    pub fn with_incumbent(root_node: N, incumbent: B) -> Self {
+        let container = bbs_state(root_node)
+            .into_container()
+            .with_incumbent(incumbent);
+        Self {
+            state: Reachable::new(container),
+            _node: PhantomData,
+        }
+    }
+}
+
+impl<N, C, E>
+    BranchAndBoundTraversal<N, BranchAndBoundState<C, N, (), Option<C>, AbsoluteTolerance<E>>>
+where
+    C: Copy + Ord + Sub<Output = E>,
+    E: Ord,
+    N: TreeNode<Cost = C> + LowerBound<Cost = C>,
+{
+    /// Creates a new `BranchAndBoundTraversal` instance like [`new`](BranchAndBoundTraversal::new), but pruning the
+    /// nodes whose lower bound is within `epsilon` of the best leaf found so far.
+    ///
+    /// This avoids expanding nodes which can only improve on the best leaf by a rounding error when the costs are
+    /// floating-point numbers, e.g. [`NotNan`](crate::NotNan). Exhausting the traversal then only proves that the
+    /// best leaf is within `epsilon` of the optimum.
+    ///
+    /// # Parameters
+    /// - `root_node`: The starting node for the traversal.
+    /// - `epsilon`: The non-negative improvement below which a node is not worth expanding, measured like the
+    ///   difference of two costs.
+    ///
+    /// # Returns
+    /// A new `BranchAndBoundTraversal` iterator.
+    pub fn with_tolerance(root_node: N, epsilon: E) -> Self {
+        let container = bbs_state(root_node)
+            .into_container()
+            .with_tolerance(AbsoluteTolerance(epsilon));
+        Self {
+            state: Reachable::new(container),
+            _node: PhantomData,
+        }
+    }
+}
+
+impl<N, C> BranchAndBoundTraversal<N, BranchAndBoundClosedSetState<C, N, <N as StateKey>::Key>>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + LowerBound<Cost = C> + StateKey,
+{
+    /// Creates a new `BranchAndBoundTraversal` instance like [`new`](BranchAndBoundTraversal::new), but with duplicate detection.
+    ///
+    /// Nodes whose state key has already been visited are skipped without being expanded, so each
+    /// state is visited at most once even if several paths of the tree lead to it.
+    ///
+    /// # Parameters
+    /// - `root_node`: The starting node for the traversal.
+    ///
+    /// # Returns
+    /// A new `BranchAndBoundTraversal` iterator.
+    pub fn with_closed_set(root_node: N) -> Self {
+        let key_fn: fn(&N) -> <N as StateKey>::Key = |n| n.state_key();
+        Self {
+            state: dedup_reach(bbs_state(root_node), key_fn),
+            _node: PhantomData,
+        }
+    }
+}
+
+impl<N, C>
+    BranchAndBoundTraversal<
+        N,
+        BranchAndBoundState<
+            C,
+            N,
+            DominanceStoreOf<N, <N as Dominance>::Key, <N as Dominance>::Label>,
+            Option<C>,
+            (),
+        >,
+    >
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + LowerBound<Cost = C> + Dominance,
+{
+    /// Creates a new `BranchAndBoundTraversal` instance like [`new`](BranchAndBoundTraversal::new), but with dominance pruning.
+    ///
+    /// Successors dominated by a node seen before with the same dominance key are discarded
+    /// instead of being pushed, as decided by [`Dominance::dominates`].
+    ///
+    /// # Parameters
+    /// - `root_node`: The starting node for the traversal.
+    ///
+    /// # Returns
+    /// A new `BranchAndBoundTraversal` iterator.
+    pub fn with_dominance(root_node: N) -> Self {
+        let key_fn: fn(&N) -> <N as Dominance>::Key = |n| n.dominance_key();
+        let label_fn: fn(&N) -> <N as Dominance>::Label = |n| n.label();
+        let dominates_fn: fn(&<N as Dominance>::Label, &<N as Dominance>::Label) -> bool =
+            N::dominates;
+        let dominance = DominanceStore::new(key_fn, label_fn, dominates_fn);
+        let container = bbs_state(root_node)
+            .into_container()
+            .with_dominance(dominance);
+        Self {
+            state: Reachable::new(container),
+            _node: PhantomData,
+        }
+    }
+}
+
+impl<N, C> BranchAndBoundTraversal<N, LazyBranchAndBoundState<C, N, N::Children>>
+where
+    C: Copy + Ord,
+    N: LazyChildren<Cost = C> + LowerBound<Cost = C>,
+{
+    /// Creates a new `BranchAndBoundTraversal` instance like [`new`](BranchAndBoundTraversal::new), but generating the child nodes one
+    /// at a time.
+    ///
+    /// Each child is generated right before being visited, and pruned right away if its lower bound is not better
+    /// than the best leaf found so far.
+    ///
+    /// # Parameters
+    /// - `root_node`: The starting node for the traversal.
+    ///
+    /// # Returns
+    /// A new `BranchAndBoundTraversal` iterator.
+    pub fn lazy(root_node: N) -> Self {
+        let successor_fn: fn(&N) -> N::Children = |n| n.lazy_child_nodes();
+        let leaf_check_fn: fn(&N) -> bool = |n| n.is_leaf();
+        let cost_fn: fn(&N) -> Option<C> = |n| n.cost();
+        let lower_bound_fn: fn(&N) -> Option<C> = |n| n.cost_lb();
+        let state = lazy_bbs_reach(
+            root_node,
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+        );
+        Self {
+            state,
+            _node: PhantomData,
+        }
+    }
+
+    /// Creates a new `BranchAndBoundTraversal` instance like [`lazy`](BranchAndBoundTraversal::lazy), for nodes generating their
+    /// children in order of non-decreasing lower bound.
+    ///
+    /// Once a child is pruned, its remaining siblings, whose lower bounds are not better, are discarded without
+    /// being generated. If the children are not generated in this order, better leaves may be missed.
+    ///
+    /// # Parameters
+    /// - `root_node`: The starting node for the traversal.
+    ///
+    /// # Returns
+    /// A new `BranchAndBoundTraversal` iterator.
+    pub fn lazy_ordered(root_node: N) -> Self {
+        let successor_fn: fn(&N) -> N::Children = |n| n.lazy_child_nodes();
+        let leaf_check_fn: fn(&N) -> bool = |n| n.is_leaf();
+        let cost_fn: fn(&N) -> Option<C> = |n| n.cost();
+        let lower_bound_fn: fn(&N) -> Option<C> = |n| n.cost_lb();
+        let state = lazy_bbs_ordered_reach(
             root_node,
-            |n: &N| n.generate_child_nodes(),
-            |n: &N| n.is_leaf(),
-            |n: &N| n.cost(),
-            |n: &N| n.cost_lb(),
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
         );
         Self {
-            state: Box::new(state),
+            state,
+            _node: PhantomData,
         }
     }
 }
 
-impl<N> Iterator for BranchAndBoundTraversal<N> {
+impl<N: TreeNode, S: Iterator<Item = N>> Iterator for BranchAndBoundTraversal<N, S> {
     type Item = N;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.state.next()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint()
+    }
 }
 
-impl<N> FusedIterator for BranchAndBoundTraversal<N> {}
+impl<N: TreeNode, S: FusedIterator<Item = N>> FusedIterator for BranchAndBoundTraversal<N, S> {}
+
+impl<N: TreeNode, S: Frontier> Frontier for BranchAndBoundTraversal<N, S> {
+    fn frontier_size(&self) -> Option<usize> {
+        self.state.frontier_size()
+    }
+}
@@ -0,0 +1,104 @@
+use std::iter::FusedIterator;
+
+use crate::node::{LowerBound, TreeNode};
+
+use super::functional::{BestFirstBranchAndBoundContainer, Frontier, Reachable, bfbb_reach};
+
+type BestFirstBranchAndBoundState<C, N> = Reachable<
+    BestFirstBranchAndBoundContainer<
+        C,
+        N,
+        fn(&N) -> Vec<N>,
+        fn(&N) -> bool,
+        fn(&N) -> Option<C>,
+        fn(&N) -> Option<C>,
+    >,
+>;
+
+/// Best-First Branch-and-Bound traversal implementation.
+pub struct BestFirstBranchAndBoundTraversal<N: TreeNode> {
+    state: BestFirstBranchAndBoundState<N::Cost, N>,
+}
+
+impl<C, N> BestFirstBranchAndBoundTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + LowerBound<Cost = C>,
+{
+    /// Creates a new `BestFirstBranchAndBoundTraversal` instance that performs a best-first branch-and-bound search
+    /// starting from the given root node.
+    ///
+    /// The node with the lowest lower bound is always expanded first, and nodes whose lower bound is not better
+    /// than the best leaf found so far are pruned. With `dive_first`, the traversal first dives depth-first until
+    /// a leaf is found and then proceeds best-first.
+    ///
+    /// # Parameters
+    /// - `root_node`: The starting node for the traversal.
+    /// - `dive_first`: Whether to dive depth-first until the first leaf is found.
+    ///
+    /// # Returns
+    /// A new `BestFirstBranchAndBoundTraversal` iterator.
+    pub fn new(root_node: N, dive_first: bool) -> Self {
+        let successor_fn: fn(&N) -> Vec<N> = |n| n.generate_child_nodes();
+        let leaf_check_fn: fn(&N) -> bool = |n| n.is_leaf();
+        let cost_fn: fn(&N) -> Option<C> = |n| n.cost();
+        let lower_bound_fn: fn(&N) -> Option<C> = |n| n.cost_lb();
+        let state = bfbb_reach(
+            root_node,
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            dive_first,
+        );
+        Self { state }
+    }
+
+    /// Returns the cost of the best leaf found so far.
+    pub fn best_cost(&self) -> Option<C> {
+        self.state.container().best_cost()
+    }
+
+    /// Returns `true` if there is no node left to visit, which means the best leaf found is optimal.
+    pub fn is_exhausted(&self) -> bool {
+        self.state.container().is_exhausted()
+    }
+
+    /// Returns a lower bound on the cost of every leaf, including those not visited yet.
+    pub fn global_lower_bound(&self) -> Option<C> {
+        self.state.container().global_lower_bound()
+    }
+}
+
+impl<C, N> Iterator for BestFirstBranchAndBoundTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + LowerBound<Cost = C>,
+{
+    type Item = N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.state.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint()
+    }
+}
+
+impl<C, N> FusedIterator for BestFirstBranchAndBoundTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + LowerBound<Cost = C>,
+{
+}
+
+impl<C, N> Frontier for BestFirstBranchAndBoundTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + LowerBound<Cost = C>,
+{
+    fn frontier_size(&self) -> Option<usize> {
+        self.state.frontier_size()
+    }
+}
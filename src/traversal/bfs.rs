@@ -1,18 +1,27 @@
-use std::iter::FusedIterator;
+use std::{iter::FusedIterator, marker::PhantomData};
 
-use crate::node::TreeNode;
+use crate::node::{StateKey, TreeNode};
 
-use super::functional::bfs_reach;
+use super::functional::{
+    BreadthFirstContainer, ClosedSetContainer, Frontier, Reachable, bfs_reach, dedup_reach,
+};
+
+type BreadthFirstState<N> = Reachable<BreadthFirstContainer<N, fn(&N) -> Vec<N>>>;
+
+type BreadthFirstClosedSetState<N, K> = Reachable<
+    ClosedSetContainer<BreadthFirstContainer<N, fn(&N) -> Vec<N>>, K, (), fn(&N) -> K, fn(&N)>,
+>;
 
 /// Breadth-First traversal implementation.
-pub struct BreadthFirstTraversal<N> {
-    state: Box<dyn FusedIterator<Item = N>>,
+pub struct BreadthFirstTraversal<N, S = BreadthFirstState<N>> {
+    state: S,
+    _node: PhantomData<fn() -> N>,
 }
 
 impl<C, N> BreadthFirstTraversal<N>
 where
-    C: Copy + Ord + 'static,
-    N: TreeNode<Cost = C> + 'static,
+    C: Copy + Ord,
+    N: TreeNode<Cost = C>,
 {
     /// Creates a new `BreadthFirstTraversal` instance that performs a breadth-first search starting from the given root node.
     ///
@@ -24,19 +33,58 @@ where
     /// # Returns
     /// A new `BreadthFirstTraversal` iterator.
     pub fn new(root_node: N) -> Self {
-        let state = bfs_reach(root_node, |n: &N| n.generate_child_nodes());
+        let successor_fn: fn(&N) -> Vec<N> = |n| n.generate_child_nodes();
+        let state = bfs_reach(root_node, successor_fn);
         Self {
-            state: Box::new(state),
+            state,
+            _node: PhantomData,
         }
     }
 }
 
-impl<N> Iterator for BreadthFirstTraversal<N> {
+impl<C, N> BreadthFirstTraversal<N, BreadthFirstClosedSetState<N, N::Key>>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + StateKey,
+{
+    /// Creates a new `BreadthFirstTraversal` instance like [`new`](BreadthFirstTraversal::new), but with duplicate
+    /// detection.
+    ///
+    /// Nodes whose state key has already been visited are skipped without being expanded, so each
+    /// state is visited at most once even if several paths of the tree lead to it.
+    ///
+    /// # Parameters
+    /// - `root_node`: The starting node for the traversal.
+    ///
+    /// # Returns
+    /// A new `BreadthFirstTraversal` iterator.
+    pub fn with_closed_set(root_node: N) -> Self {
+        let successor_fn: fn(&N) -> Vec<N> = |n| n.generate_child_nodes();
+        let key_fn: fn(&N) -> N::Key = |n| n.state_key();
+        let state = dedup_reach(bfs_reach(root_node, successor_fn), key_fn);
+        Self {
+            state,
+            _node: PhantomData,
+        }
+    }
+}
+
+impl<N, S: Iterator<Item = N>> Iterator for BreadthFirstTraversal<N, S> {
     type Item = N;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.state.next()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint()
+    }
 }
 
-impl<N> FusedIterator for BreadthFirstTraversal<N> {}
+impl<N, S: FusedIterator<Item = N>> FusedIterator for BreadthFirstTraversal<N, S> {}
+
+impl<N, S: Frontier> Frontier for BreadthFirstTraversal<N, S> {
+    fn frontier_size(&self) -> Option<usize> {
+        self.state.frontier_size()
+    }
+}
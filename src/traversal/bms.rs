@@ -1,18 +1,26 @@
-use std::iter::FusedIterator;
+use std::{iter::FusedIterator, marker::PhantomData};
 
 use crate::node::{Priority, TreeNode};
 
-use super::functional::bms_reach;
+use super::functional::{
+    BeamContainer, Frontier, ParallelBeamContainer, Reachable, bms_reach, pbms_reach,
+};
+
+type BeamState<N, P> = Reachable<BeamContainer<N, fn(&N) -> Vec<N>, fn(&N) -> Option<P>, P>>;
+
+type ParallelBeamState<N, P> =
+    Reachable<ParallelBeamContainer<N, fn(&N) -> Vec<N>, fn(&N) -> Option<P>>>;
 
 /// Beam traversal implementation.
-pub struct BeamTraversal<N> {
-    state: Box<dyn FusedIterator<Item = N>>,
+pub struct BeamTraversal<N: Priority, S = BeamState<N, <N as Priority>::Value>> {
+    state: S,
+    _node: PhantomData<fn() -> N>,
 }
 
 impl<C, N> BeamTraversal<N>
 where
-    C: Copy + Ord + 'static,
-    N: TreeNode<Cost = C> + Priority + 'static,
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + Priority,
 {
     /// Creates a new `BeamTraversal` instance that performs a beam search starting from the given root node.
     ///
@@ -27,25 +35,82 @@ where
     /// # Returns
     /// A new `BeamTraversal` iterator.
     pub fn new(root_node: N, branch_factor: usize, beam_width: usize) -> Self {
+        let successor_fn: fn(&N) -> Vec<N> = |n| n.generate_child_nodes();
+        let priority_fn: fn(&N) -> Option<N::Value> = |n| n.priority();
         let state = bms_reach(
             root_node,
-            |n: &N| n.generate_child_nodes(),
-            |n: &N| n.priority(),
+            successor_fn,
+            priority_fn,
             branch_factor,
             beam_width,
         );
         Self {
-            state: Box::new(state),
+            state,
+            _node: PhantomData,
         }
     }
 }
 
-impl<N> Iterator for BeamTraversal<N> {
+impl<C, N> BeamTraversal<N, ParallelBeamState<N, N::Value>>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + Priority + Clone + Send,
+    N::Value: Send,
+{
+    /// Creates a new `BeamTraversal` instance like [`new`](BeamTraversal::new), but expanding and scoring each level
+    /// on several threads.
+    ///
+    /// The nodes visited are the same as those of [`new`](BeamTraversal::new), in the same order. Nodes are cloned
+    /// when visited, and the successors of a whole level are generated and scored concurrently once it has been
+    /// visited.
+    ///
+    /// # Parameters
+    /// - `root_node`: The starting node for the traversal.
+    /// - `branch_factor`: The maximum number of child nodes to consider per parent node.
+    /// - `beam_width`: The maximum number of nodes to keep in the beam at each level.
+    /// - `n_threads`: The number of threads expanding a level, or `0` to use the available parallelism.
+    ///
+    /// # Returns
+    /// A new `BeamTraversal` iterator.
+    pub fn parallel(
+        root_node: N,
+        branch_factor: usize,
+        beam_width: usize,
+        n_threads: usize,
+    ) -> Self {
+        let successor_fn: fn(&N) -> Vec<N> = |n| n.generate_child_nodes();
+        let priority_fn: fn(&N) -> Option<N::Value> = |n| n.priority();
+        let state = pbms_reach(
+            root_node,
+            successor_fn,
+            priority_fn,
+            branch_factor,
+            beam_width,
+            n_threads,
+        );
+        Self {
+            state,
+            _node: PhantomData,
+        }
+    }
+}
+
+impl<N: Priority, S: Iterator<Item = N>> Iterator for BeamTraversal<N, S> {
     type Item = N;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.state.next()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint()
+    }
 }
 
-impl<N> FusedIterator for BeamTraversal<N> {}
+impl<N: Priority, S: FusedIterator<Item = N>> FusedIterator for BeamTraversal<N, S> {}
+
+impl<N: Priority, S: Frontier> Frontier for BeamTraversal<N, S> {
+    fn frontier_size(&self) -> Option<usize> {
+        self.state.frontier_size()
+    }
+}
@@ -0,0 +1,98 @@
+use std::iter::FusedIterator;
+
+use crate::node::{LowerBound, Priority, TreeNode};
+
+use super::functional::{BeamStackContainer, Frontier, Reachable, bss_reach};
+
+type BeamStackState<C, N, P> = Reachable<
+    BeamStackContainer<
+        C,
+        N,
+        fn(&N) -> Vec<N>,
+        fn(&N) -> bool,
+        fn(&N) -> Option<C>,
+        fn(&N) -> Option<C>,
+        fn(&N) -> Option<P>,
+        P,
+    >,
+>;
+
+/// Beam Stack traversal implementation.
+pub struct BeamStackTraversal<N: TreeNode + Priority> {
+    state: BeamStackState<N::Cost, N, N::Value>,
+}
+
+impl<C, N> BeamStackTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + LowerBound<Cost = C> + Priority,
+{
+    /// Creates a new `BeamStackTraversal` instance that performs a beam stack search starting from the given root node.
+    ///
+    /// Beam stack search starts like a beam search, keeping the best `beam_width` nodes at each level, but remembers
+    /// the candidates that did not fit in the beam and backtracks into them later. Combined with lower bound pruning,
+    /// it finds a first solution quickly and, given enough time, terminates with a proven optimum.
+    ///
+    /// # Parameters
+    /// - `root_node`: The starting node for the traversal.
+    /// - `beam_width`: The maximum number of nodes expanded together at each level. It must be positive.
+    ///
+    /// # Returns
+    /// A new `BeamStackTraversal` iterator.
+    pub fn new(root_node: N, beam_width: usize) -> Self {
+        let successor_fn: fn(&N) -> Vec<N> = |n| n.generate_child_nodes();
+        let leaf_check_fn: fn(&N) -> bool = |n| n.is_leaf();
+        let cost_fn: fn(&N) -> Option<C> = |n| n.cost();
+        let lower_bound_fn: fn(&N) -> Option<C> = |n| n.cost_lb();
+        let priority_fn: fn(&N) -> Option<N::Value> = |n| n.priority();
+        let state = bss_reach(
+            root_node,
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            priority_fn,
+            beam_width,
+        );
+        Self { state }
+    }
+
+    /// Returns `true` if the traversal has exhausted the search space, which means the best leaf yielded so far is
+    /// optimal.
+    pub fn is_exhausted(&self) -> bool {
+        self.state.container().is_exhausted()
+    }
+}
+
+impl<C, N> Iterator for BeamStackTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + LowerBound<Cost = C> + Priority,
+{
+    type Item = N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.state.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint()
+    }
+}
+
+impl<C, N> FusedIterator for BeamStackTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + LowerBound<Cost = C> + Priority,
+{
+}
+
+impl<C, N> Frontier for BeamStackTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + LowerBound<Cost = C> + Priority,
+{
+    fn frontier_size(&self) -> Option<usize> {
+        self.state.frontier_size()
+    }
+}
@@ -2,7 +2,9 @@ use std::{iter::FusedIterator, time::Duration};
 
 use crate::node::TreeNode;
 
-use super::functional;
+use super::functional::{
+    self, Frontier, ImprovingLeaves, Objectives, SearchObserver, SearchOutcome, StopCondition,
+};
 
 /// Trait defining the interface for tree traversal algorithms.
 /// This trait is an alias for `FusedIterator` over tree nodes.
@@ -64,3 +66,209 @@ pub fn find_best<N: TreeNode>(
 ) -> Option<(<N as TreeNode>::Cost, N)> {
     traverse(traversal, max_ops, time_limit, 1, callback).pop()
 }
+
+/// Traverses the tree using the provided traversal iterator like [`traverse`], and reports how the search went.
+///
+/// # Parameters
+/// - `traversal`: A mutable reference to a traversal iterator.
+/// - `max_ops`: The maximum number of nodes to process.
+/// - `time_limit`: The maximum time allowed for the traversal.
+/// - `queue_size`: The maximum number of best nodes to return.
+/// - `callback`: A mutable callback invoked as `callback(n_step, &node)` for each visited node.
+///
+/// # Returns
+/// A [`SearchOutcome`] holding the best leaf nodes along with the termination reason and statistics of the search.
+pub fn traverse_with_outcome<N: TreeNode>(
+    traversal: &mut impl Traversal<N>,
+    max_ops: usize,
+    time_limit: Duration,
+    queue_size: usize,
+    callback: impl FnMut(usize, &N),
+) -> SearchOutcome<<N as TreeNode>::Cost, N> {
+    functional::traverse_with_outcome(
+        traversal,
+        |n: &N| n.is_leaf(),
+        |n: &N| n.cost(),
+        max_ops,
+        time_limit,
+        queue_size,
+        callback,
+    )
+}
+
+/// Finds the best leaf node in the tree using the provided traversal iterator like [`find_best`], and reports
+/// how the search went.
+///
+/// # Parameters
+/// - `traversal`: A mutable reference to a traversal iterator.
+/// - `max_ops`: The maximum number of nodes to process.
+/// - `time_limit`: The maximum time allowed for the traversal.
+/// - `callback`: A mutable callback invoked as `callback(n_step, &node)` for each visited node.
+///
+/// # Returns
+/// A [`SearchOutcome`] holding at most one solution, the best leaf node, along with the termination reason and
+/// statistics of the search.
+pub fn find_best_with_outcome<N: TreeNode>(
+    traversal: &mut impl Traversal<N>,
+    max_ops: usize,
+    time_limit: Duration,
+    callback: impl FnMut(usize, &N),
+) -> SearchOutcome<<N as TreeNode>::Cost, N> {
+    traverse_with_outcome(traversal, max_ops, time_limit, 1, callback)
+}
+
+/// Traverses the tree using the provided traversal iterator until the given stop condition is met, and reports how
+/// the search went.
+///
+/// # Parameters
+/// - `traversal`: A mutable reference to a traversal iterator.
+/// - `queue_size`: The maximum number of best nodes to return.
+/// - `stop_condition`: The condition to stop the traversal, e.g. `Any(MaxOps(1000), TargetCost(0))`.
+/// - `callback`: A mutable callback invoked as `callback(n_step, &node)` for each visited node.
+///
+/// # Returns
+/// A [`SearchOutcome`] holding the best leaf nodes along with the termination reason and statistics of the search.
+pub fn traverse_until<N: TreeNode>(
+    traversal: &mut (impl Traversal<N> + Frontier),
+    queue_size: usize,
+    stop_condition: impl StopCondition<<N as TreeNode>::Cost>,
+    callback: impl FnMut(usize, &N),
+) -> SearchOutcome<<N as TreeNode>::Cost, N> {
+    functional::traverse_until(
+        traversal,
+        |n: &N| n.is_leaf(),
+        |n: &N| n.cost(),
+        queue_size,
+        stop_condition,
+        callback,
+    )
+}
+
+/// Finds the best leaf node in the tree using the provided traversal iterator until the given stop condition is met,
+/// and reports how the search went.
+///
+/// # Parameters
+/// - `traversal`: A mutable reference to a traversal iterator.
+/// - `stop_condition`: The condition to stop the traversal, e.g. `Any(MaxOps(1000), TargetCost(0))`.
+/// - `callback`: A mutable callback invoked as `callback(n_step, &node)` for each visited node.
+///
+/// # Returns
+/// A [`SearchOutcome`] holding at most one solution, the best leaf node, along with the termination reason and
+/// statistics of the search.
+pub fn find_best_until<N: TreeNode>(
+    traversal: &mut (impl Traversal<N> + Frontier),
+    stop_condition: impl StopCondition<<N as TreeNode>::Cost>,
+    callback: impl FnMut(usize, &N),
+) -> SearchOutcome<<N as TreeNode>::Cost, N> {
+    traverse_until(traversal, 1, stop_condition, callback)
+}
+
+/// Traverses the tree using the provided traversal iterator until the given stop condition is met, notifying an
+/// observer of the visited nodes, the evaluated leaves, the improvements of the best leaf and the outcome.
+///
+/// # Parameters
+/// - `traversal`: A mutable reference to a traversal iterator.
+/// - `queue_size`: The maximum number of best nodes to return.
+/// - `stop_condition`: The condition to stop the traversal, e.g. `Any(MaxOps(1000), TargetCost(0))`.
+/// - `observer`: The observer notified of the events of the traversal.
+///
+/// # Returns
+/// A [`SearchOutcome`] holding the best leaf nodes along with the termination reason and statistics of the search.
+pub fn traverse_observed<N: TreeNode>(
+    traversal: &mut (impl Traversal<N> + Frontier),
+    queue_size: usize,
+    stop_condition: impl StopCondition<<N as TreeNode>::Cost>,
+    observer: impl SearchObserver<N, <N as TreeNode>::Cost>,
+) -> SearchOutcome<<N as TreeNode>::Cost, N> {
+    functional::traverse_observed(
+        traversal,
+        |n: &N| n.is_leaf(),
+        |n: &N| n.cost(),
+        queue_size,
+        stop_condition,
+        observer,
+    )
+}
+
+/// Finds the best leaf node in the tree using the provided traversal iterator until the given stop condition is met,
+/// notifying an observer of the events of the traversal.
+///
+/// # Parameters
+/// - `traversal`: A mutable reference to a traversal iterator.
+/// - `stop_condition`: The condition to stop the traversal, e.g. `Any(MaxOps(1000), TargetCost(0))`.
+/// - `observer`: The observer notified of the events of the traversal.
+///
+/// # Returns
+/// A [`SearchOutcome`] holding at most one solution, the best leaf node, along with the termination reason and
+/// statistics of the search.
+pub fn find_best_observed<N: TreeNode>(
+    traversal: &mut (impl Traversal<N> + Frontier),
+    stop_condition: impl StopCondition<<N as TreeNode>::Cost>,
+    observer: impl SearchObserver<N, <N as TreeNode>::Cost>,
+) -> SearchOutcome<<N as TreeNode>::Cost, N> {
+    traverse_observed(traversal, 1, stop_condition, observer)
+}
+
+/// Traverses the tree using the provided traversal iterator, collecting the Pareto front of the leaf nodes.
+///
+/// This function wraps the functional `traverse_pareto` with default leaf and cost functions based on the `TreeNode`
+/// trait, whose cost is a vector of objectives, e.g. a `(cost, time)` tuple. Since `TreeNode::Cost` is `Copy`, the
+/// objectives must be a tuple or an array; `Vec` objectives are only supported by the functional `traverse_pareto`.
+///
+/// # Parameters
+/// - `traversal`: A mutable reference to a traversal iterator.
+/// - `max_ops`: The maximum number of nodes to process.
+/// - `time_limit`: The maximum time allowed for the traversal.
+/// - `callback`: A mutable callback invoked as `callback(n_step, &node)` for each visited node.
+///
+/// # Returns
+/// A vector of tuples containing the objectives and the node of the non-dominated leaves, sorted lexicographically
+/// by objectives.
+pub fn traverse_pareto<N>(
+    traversal: &mut impl Traversal<N>,
+    max_ops: usize,
+    time_limit: Duration,
+    callback: impl FnMut(usize, &N),
+) -> Vec<(<N as TreeNode>::Cost, N)>
+where
+    N: TreeNode,
+    N::Cost: Objectives,
+{
+    functional::traverse_pareto(
+        traversal,
+        |n: &N| n.is_leaf(),
+        |n: &N| n.cost(),
+        max_ops,
+        time_limit,
+        callback,
+    )
+}
+
+/// Creates an iterator over the leaf nodes that strictly improve on every leaf found before by the provided traversal.
+///
+/// This function wraps the functional `improving_leaves` with default leaf and cost functions based on the `TreeNode`
+/// trait. Each item is yielded as soon as it is found, so that progressively better solutions can be shown while the
+/// search goes on.
+///
+/// # Parameters
+/// - `traversal`: A traversal iterator, or a mutable reference to one.
+/// - `max_ops`: The maximum number of nodes to process.
+/// - `time_limit`: The maximum time allowed for the traversal, counted from the first call to `next`.
+///
+/// # Returns
+/// An iterator of the improvements of the best leaf, with their cost, operation index and elapsed time, along with the
+/// leaf nodes themselves.
+#[allow(clippy::type_complexity)]
+pub fn improving_leaves<N: TreeNode, T: Traversal<N>>(
+    traversal: T,
+    max_ops: usize,
+    time_limit: Duration,
+) -> ImprovingLeaves<T, fn(&N) -> bool, fn(&N) -> Option<N::Cost>, N::Cost> {
+    functional::improving_leaves(
+        traversal,
+        |n: &N| n.is_leaf(),
+        |n: &N| n.cost(),
+        max_ops,
+        time_limit,
+    )
+}
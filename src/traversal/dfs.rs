@@ -1,18 +1,30 @@
-use std::iter::FusedIterator;
+use std::{iter::FusedIterator, marker::PhantomData};
 
-use crate::node::TreeNode;
+use crate::node::{LazyChildren, StateKey, TreeNode};
 
-use super::functional::dfs_reach;
+use super::functional::{
+    ClosedSetContainer, DepthFirstContainer, Frontier, LazyDepthFirstContainer, Reachable,
+    dedup_reach, dfs_reach, lazy_dfs_reach,
+};
+
+type DepthFirstState<N> = Reachable<DepthFirstContainer<N, fn(&N) -> Vec<N>>>;
+
+type DepthFirstClosedSetState<N, K> = Reachable<
+    ClosedSetContainer<DepthFirstContainer<N, fn(&N) -> Vec<N>>, K, (), fn(&N) -> K, fn(&N)>,
+>;
+
+type LazyDepthFirstState<N, I> = Reachable<LazyDepthFirstContainer<N, fn(&N) -> I, I>>;
 
 /// Depth-First traversal implementation.
-pub struct DepthFirstTraversal<N> {
-    state: Box<dyn FusedIterator<Item = N>>,
+pub struct DepthFirstTraversal<N, S = DepthFirstState<N>> {
+    state: S,
+    _node: PhantomData<fn() -> N>,
 }
 
 impl<C, N> DepthFirstTraversal<N>
 where
-    C: Copy + Ord + 'static,
-    N: TreeNode<Cost = C> + 'static,
+    C: Copy + Ord,
+    N: TreeNode<Cost = C>,
 {
     /// Creates a new `DepthFirstTraversal` instance that performs a depth-first search starting from the given root node.
     ///
@@ -24,19 +36,84 @@ where
     /// # Returns
     /// A new `DepthFirstTraversal` iterator.
     pub fn new(root_node: N) -> Self {
-        let state = dfs_reach(root_node, |n: &N| n.generate_child_nodes());
+        let successor_fn: fn(&N) -> Vec<N> = |n| n.generate_child_nodes();
+        let state = dfs_reach(root_node, successor_fn);
+        Self {
+            state,
+            _node: PhantomData,
+        }
+    }
+}
+
+impl<C, N> DepthFirstTraversal<N, DepthFirstClosedSetState<N, N::Key>>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + StateKey,
+{
+    /// Creates a new `DepthFirstTraversal` instance like [`new`](DepthFirstTraversal::new), but with duplicate
+    /// detection.
+    ///
+    /// Nodes whose state key has already been visited are skipped without being expanded, so each
+    /// state is visited at most once even if several paths of the tree lead to it.
+    ///
+    /// # Parameters
+    /// - `root_node`: The starting node for the traversal.
+    ///
+    /// # Returns
+    /// A new `DepthFirstTraversal` iterator.
+    pub fn with_closed_set(root_node: N) -> Self {
+        let successor_fn: fn(&N) -> Vec<N> = |n| n.generate_child_nodes();
+        let key_fn: fn(&N) -> N::Key = |n| n.state_key();
+        let state = dedup_reach(dfs_reach(root_node, successor_fn), key_fn);
+        Self {
+            state,
+            _node: PhantomData,
+        }
+    }
+}
+
+impl<C, N> DepthFirstTraversal<N, LazyDepthFirstState<N, N::Children>>
+where
+    C: Copy + Ord,
+    N: LazyChildren<Cost = C>,
+{
+    /// Creates a new `DepthFirstTraversal` instance like [`new`](DepthFirstTraversal::new), but generating the child
+    /// nodes one at a time.
+    ///
+    /// Only an iterator over the children of each node of the current path is kept, and each child is generated
+    /// right before being visited. Children are visited in the order they are generated.
+    ///
+    /// # Parameters
+    /// - `root_node`: The starting node for the traversal.
+    ///
+    /// # Returns
+    /// A new `DepthFirstTraversal` iterator.
+    pub fn lazy(root_node: N) -> Self {
+        let successor_fn: fn(&N) -> N::Children = |n| n.lazy_child_nodes();
+        let state = lazy_dfs_reach(root_node, successor_fn);
         Self {
-            state: Box::new(state),
+            state,
+            _node: PhantomData,
         }
     }
 }
 
-impl<N> Iterator for DepthFirstTraversal<N> {
+impl<N, S: Iterator<Item = N>> Iterator for DepthFirstTraversal<N, S> {
     type Item = N;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.state.next()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint()
+    }
 }
 
-impl<N> FusedIterator for DepthFirstTraversal<N> {}
+impl<N, S: FusedIterator<Item = N>> FusedIterator for DepthFirstTraversal<N, S> {}
+
+impl<N, S: Frontier> Frontier for DepthFirstTraversal<N, S> {
+    fn frontier_size(&self) -> Option<usize> {
+        self.state.frontier_size()
+    }
+}
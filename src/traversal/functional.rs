@@ -1,17 +1,72 @@
 //! functional interfaces for tree traversal algorithms
 
+mod abms;
+mod astar;
 mod bbs;
+mod bfbb;
 mod bfs;
 mod bms;
+mod bss;
 mod common;
+mod dedup;
 mod dfs;
+mod dominance;
 mod gds;
+mod idastar;
+mod iddfs;
+mod improving;
+mod incremental;
+mod incumbent;
+mod lazy;
+mod lds;
+mod mcts;
+mod observer;
+mod pareto;
+mod pbbs;
+mod pbms;
 mod pfs;
+mod stop;
 
-pub use bbs::{BranchAndBoundContainer, bbs, bbs_reach};
-pub use bfs::{BreadthFirstContainer, bfs, bfs_reach};
-pub use bms::{BeamContainer, bms, bms_reach};
-pub use common::{NodeContainer, Reachable, find_best, traverse};
-pub use dfs::{DepthFirstContainer, dfs, dfs_reach};
-pub use gds::{GreedyContainer, gds, gds_reach};
-pub use pfs::{PriorityFirstContainer, pfs, pfs_reach};
+pub use abms::{AnytimeBeamContainer, abms, abms_reach};
+pub use astar::{AStarContainer, astar, astar_reach};
+pub use bbs::{
+    AbsoluteTolerance, BranchAndBoundContainer, BranchAndBoundResult, Tolerance, bbs,
+    bbs_dominance, bbs_dominance_reach, bbs_incumbent, bbs_incumbent_reach, bbs_reach, bbs_solve,
+    bbs_until,
+};
+pub use bfbb::{BestFirstBranchAndBoundContainer, bfbb, bfbb_reach, bfbb_solve};
+pub use bfs::{BreadthFirstContainer, bfs, bfs_reach, bfs_until};
+pub use bms::{BeamContainer, bms, bms_reach, bms_until};
+pub use bss::{BeamStackContainer, bss, bss_reach};
+pub use common::{
+    Frontier, Improvement, NodeContainer, Reachable, SearchOutcome, TerminationReason, find_best,
+    find_best_observed, find_best_until, find_best_with_outcome, traverse, traverse_observed,
+    traverse_until, traverse_with_outcome,
+};
+pub use dedup::{ClosedSetContainer, dedup_by_cost_reach, dedup_reach};
+pub use dfs::{DepthFirstContainer, dfs, dfs_reach, dfs_until};
+pub use dominance::{DominanceFilter, DominanceStore};
+pub use gds::{GreedyContainer, gds, gds_reach, gds_until};
+pub use idastar::{IterativeDeepeningAStarContainer, idastar, idastar_reach};
+pub use iddfs::{IterativeDeepeningContainer, iddfs, iddfs_reach};
+pub use improving::{ImprovingLeaves, improving_leaves};
+pub use incremental::{incremental_bbs, incremental_dfs, incremental_lds};
+pub use incumbent::{Incumbent, SharedIncumbent};
+pub use lazy::{
+    LazyBranchAndBoundContainer, LazyDepthFirstContainer, lazy_bbs, lazy_bbs_ordered_reach,
+    lazy_bbs_reach, lazy_dfs, lazy_dfs_reach,
+};
+pub use lds::{DiscrepancyKind, LimitedDiscrepancyContainer, dds, dds_reach, lds, lds_reach};
+pub use mcts::{MonteCarloContainer, mcts, mcts_reach};
+pub use observer::{ExpansionObserver, PruneReason, SearchObserver};
+pub use pareto::{
+    Objectives, ParetoArchive, ParetoBranchAndBoundContainer, pareto_bbs, pareto_bbs_reach,
+    traverse_pareto,
+};
+pub use pbbs::{pbbs, pbbs_solve, pbbs_solve_with};
+pub use pbms::{ParallelBeamContainer, pbms, pbms_reach};
+pub use pfs::{PriorityFirstContainer, pfs, pfs_reach, pfs_until};
+pub use stop::{
+    All, Any, Cancel, MaxFrontier, MaxOps, MaxSolutions, SearchProgress, Stagnation, StopCondition,
+    TargetCost, TimeLimit,
+};
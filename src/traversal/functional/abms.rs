@@ -0,0 +1,291 @@
+//! Anytime Beam Search
+
+use std::time::Duration;
+
+use super::{
+    bms::BeamContainer,
+    common::{NodeContainer, Reachable, find_best},
+};
+
+/// A container for Anytime Beam Search traversal.
+///
+/// The container runs a beam search like [`BeamContainer`]. When the beam empties, the search
+/// restarts from the root with the beam width given by `width_growth_fn(beam_width)`. Restarts
+/// stop when the previous run did not discard any node because of the beam width (so a wider
+/// beam would explore the same nodes) or when the growth function does not increase the width.
+pub struct AnytimeBeamContainer<N, FN, FP, P: Ord, FW> {
+    root: N,
+    beam: BeamContainer<N, FN, FP, P>,
+    beam_width: usize,
+    width_growth_fn: FW,
+}
+
+impl<N, IN, FN, FP, P, FW> AnytimeBeamContainer<N, FN, FP, P, FW>
+where
+    N: Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FP: Fn(&N) -> Option<P>,
+    P: Ord + Copy,
+    FW: Fn(usize) -> usize,
+{
+    /// Creates a new `AnytimeBeamContainer` with the given parameters.
+    pub fn new(
+        start: N,
+        successor_fn: FN,
+        priority_fn: FP,
+        branch_factor: usize,
+        beam_width: usize,
+        width_growth_fn: FW,
+    ) -> Self {
+        Self {
+            beam: BeamContainer::new(
+                start.clone(),
+                successor_fn,
+                priority_fn,
+                branch_factor,
+                beam_width,
+            ),
+            root: start,
+            beam_width,
+            width_growth_fn,
+        }
+    }
+
+    /// Returns the beam width of the current run.
+    pub fn beam_width(&self) -> usize {
+        self.beam_width
+    }
+}
+
+impl<N, IN, FN, FP, P, FW> NodeContainer for AnytimeBeamContainer<N, FN, FP, P, FW>
+where
+    N: Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FP: Fn(&N) -> Option<P>,
+    P: Ord + Copy,
+    FW: Fn(usize) -> usize,
+{
+    type Node = N;
+
+    fn pop(&mut self) -> Option<Self::Node> {
+        if let Some(node) = self.beam.pop() {
+            return Some(node);
+        }
+        if !self.beam.is_truncated() {
+            return None;
+        }
+        let beam_width = (self.width_growth_fn)(self.beam_width);
+        if beam_width <= self.beam_width {
+            return None;
+        }
+        self.beam_width = beam_width;
+        self.beam.restart(self.root.clone(), beam_width);
+        self.beam.pop()
+    }
+
+    fn expand_and_push(&mut self, node: &Self::Node) {
+        self.beam.expand_and_push(node);
+    }
+
+    fn frontier_size(&self) -> Option<usize> {
+        match self.beam.frontier_size() {
+            // the root is pushed again when the search restarts with a wider beam
+            Some(0)
+                if self.beam.is_truncated()
+                    && (self.width_growth_fn)(self.beam_width) > self.beam_width =>
+            {
+                Some(1)
+            }
+            size => size,
+        }
+    }
+}
+
+/// Creates an Anytime Beam Search traversal iterator starting from the given node.
+///
+/// This function initializes a lazy iterator that runs a beam search and, each time the beam
+/// empties, restarts it from the start node with a wider beam given by `width_growth_fn`
+/// (e.g. `|w| w.saturating_mul(2)` to double it). This quickly yields a first solution and
+/// then better ones as the beam grows, until the traversal is stopped by the caller or a
+/// wider beam would not change the search anymore.
+///
+/// # Parameters
+/// - `start`: The root node from which to begin the traversal.
+/// - `successor_fn`: A function that, given a node, returns an iterator over its successor nodes.
+/// - `priority_fn`: A function that evaluates a node for selection, returning `Some(priority)` where higher
+///   priorities are better, or `None` if the node cannot be evaluated.
+/// - `branch_factor`: The maximum number of successors to consider from each node.
+/// - `beam_width`: The maximum number of nodes to keep at each depth level in the first run.
+/// - `width_growth_fn`: A function that returns the beam width of the next run given the current one.
+///
+/// # Returns
+/// An iterator that yields nodes reachable from the start node in beam search order, run after run.
+/// The iterator is lazy and will only compute successors as needed.
+pub fn abms_reach<N, IN, FN, FP, P, FW>(
+    start: N,
+    successor_fn: FN,
+    priority_fn: FP,
+    branch_factor: usize,
+    beam_width: usize,
+    width_growth_fn: FW,
+) -> Reachable<AnytimeBeamContainer<N, FN, FP, P, FW>>
+where
+    N: Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FP: Fn(&N) -> Option<P>,
+    P: Ord + Copy,
+    FW: Fn(usize) -> usize,
+{
+    let container = AnytimeBeamContainer::new(
+        start,
+        successor_fn,
+        priority_fn,
+        branch_factor,
+        beam_width,
+        width_growth_fn,
+    );
+    Reachable::new(container)
+}
+
+/// Find the leaf node with the lowest cost by using Anytime Beam Search
+///
+/// - `start` is the start node.
+/// - `successor_fn` returns a list of successors for a given node.
+/// - `leaf_check_fn` checks if a node is a leaf or not
+/// - `cost_fn` returns the final cost of a leaf node
+/// - `priority_fn` returns the priority of a given node to sort and select k-best
+/// - `branch_factor` decides maximum number of branches from a node
+/// - `beam_width` decides maximum number of nodes at each depth in the first run.
+/// - `width_growth_fn` returns the beam width of the next run given the current one.
+/// - `max_ops` sets the maximum number of search operations to perform.
+/// - `time_limit` sets the time limit for the search.
+///
+/// This function returns Some of a tuple of (cost, leaf node) if found, otherwise returns None
+#[allow(clippy::too_many_arguments)]
+pub fn abms<N, IN, FN, FC, FP, C, P, FR, FW>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FR,
+    cost_fn: FC,
+    priority_fn: FP,
+    branch_factor: usize,
+    beam_width: usize,
+    width_growth_fn: FW,
+    max_ops: usize,
+    time_limit: Duration,
+) -> Option<(C, N)>
+where
+    N: Clone,
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    FC: Fn(&N) -> Option<C>,
+    FP: Fn(&N) -> Option<P>,
+    C: Ord + Copy,
+    P: Ord + Copy,
+    FR: Fn(&N) -> bool,
+    FW: Fn(usize) -> usize,
+{
+    let mut res = abms_reach(
+        start,
+        successor_fn,
+        priority_fn,
+        branch_factor,
+        beam_width,
+        width_growth_fn,
+    );
+    find_best(
+        &mut res,
+        leaf_check_fn,
+        cost_fn,
+        max_ops,
+        time_limit,
+        |_, _| {},
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{abms, abms_reach};
+
+    type Node = Vec<bool>;
+
+    #[test]
+    fn test_abms() {
+        let weights = [3, 4, 6, 5];
+        let profits = [2, 3, 2, 4];
+        let capacity = 8;
+        let total_items = weights.len();
+
+        let successor_fn = |n: &Node| {
+            if n.len() == total_items {
+                return vec![];
+            }
+
+            let total_weight: u32 = n
+                .iter()
+                .copied()
+                .enumerate()
+                .map(|(i, b)| if b { weights[i] } else { 0 })
+                .sum();
+
+            let mut children = vec![];
+
+            let mut c1 = n.clone();
+            c1.push(false);
+            children.push(c1);
+
+            let next_idx = n.len();
+            if capacity >= total_weight + weights[next_idx] {
+                let mut c2 = n.clone();
+                c2.push(true);
+                children.push(c2);
+            }
+
+            children
+        };
+
+        let total_profit = |n: &Node| {
+            let s: u32 = n
+                .iter()
+                .copied()
+                .enumerate()
+                .map(|(i, b)| if b { profits[i] } else { 0 })
+                .sum();
+            s
+        };
+
+        let cost_fn = |n: &Node| Some(u32::MAX - total_profit(n));
+        let priority_fn = |n: &Node| Some(total_profit(n));
+        let leaf_check_fn = |n: &Node| n.len() == total_items;
+        let width_growth_fn = |w: usize| w.saturating_mul(2);
+
+        // the first run with a beam width of 1 is the greedy search
+        let first_leaf = abms_reach(vec![], successor_fn, priority_fn, 2, 1, width_growth_fn)
+            .find(|n| leaf_check_fn(n))
+            .expect("ABMS should reach a leaf");
+        assert_eq!(total_profit(&first_leaf), 5);
+
+        let (cost, best_node) = abms(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            priority_fn,
+            2,
+            1,
+            width_growth_fn,
+            usize::MAX,
+            Duration::from_secs(10),
+        )
+        .expect("ABMS should find a valid solution");
+        let cost = u32::MAX - cost;
+
+        assert_eq!(cost, 6);
+        assert_eq!(best_node, vec![true, false, false, true]);
+    }
+}
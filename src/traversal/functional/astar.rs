@@ -0,0 +1,252 @@
+//! A* Search
+
+use std::{cmp::Reverse, collections::BinaryHeap, ops::Add, time::Duration};
+
+use crate::utils::ScoredItem;
+
+use super::{
+    common::{NodeContainer, Reachable},
+    find_best,
+};
+
+/// A container for A* traversal.
+///
+/// Nodes are ordered by `f(n) = g(n) + h(n)`, where `g(n)` is the path cost and `h(n)` is an
+/// admissible heuristic. Ties are broken in favor of the node with the larger path cost, i.e.
+/// the one closer to a leaf. Once a leaf node has been popped the container stops yielding
+/// nodes, since that leaf is proven to be optimal.
+pub struct AStarContainer<C: Ord, N, FN, FL, FG, FH> {
+    to_see: BinaryHeap<ScoredItem<(Reverse<C>, C), N>>,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    path_cost_fn: FG,
+    heuristic_fn: FH,
+    goal_reached: bool,
+}
+
+impl<C, N, IN, FN, FL, FG, FH> AStarContainer<C, N, FN, FL, FG, FH>
+where
+    C: Ord + Copy + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FL: Fn(&N) -> bool,
+    FG: Fn(&N) -> C,
+    FH: Fn(&N) -> Option<C>,
+{
+    /// Creates a new `AStarContainer` with the given parameters.
+    pub fn new(
+        start: N,
+        successor_fn: FN,
+        leaf_check_fn: FL,
+        path_cost_fn: FG,
+        heuristic_fn: FH,
+    ) -> Self {
+        let mut container = Self {
+            to_see: BinaryHeap::new(),
+            successor_fn,
+            leaf_check_fn,
+            path_cost_fn,
+            heuristic_fn,
+            goal_reached: false,
+        };
+        container.push(start);
+        container
+    }
+
+    /// Returns `true` if a leaf node has been reached, which means the search has finished
+    /// and the reached leaf is optimal provided that the heuristic is admissible.
+    pub fn is_goal_reached(&self) -> bool {
+        self.goal_reached
+    }
+
+    fn push(&mut self, node: N) {
+        if let Some(h) = (self.heuristic_fn)(&node) {
+            let g = (self.path_cost_fn)(&node);
+            self.to_see
+                .push(ScoredItem::from(((Reverse(g + h), g), node)));
+        }
+    }
+}
+
+impl<C, N, IN, FN, FL, FG, FH> NodeContainer for AStarContainer<C, N, FN, FL, FG, FH>
+where
+    C: Ord + Copy + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FL: Fn(&N) -> bool,
+    FG: Fn(&N) -> C,
+    FH: Fn(&N) -> Option<C>,
+{
+    type Node = N;
+
+    fn pop(&mut self) -> Option<Self::Node> {
+        if self.goal_reached {
+            return None;
+        }
+        self.to_see.pop().map(|scored| scored.into_item())
+    }
+
+    fn expand_and_push(&mut self, node: &Self::Node) {
+        if (self.leaf_check_fn)(node) {
+            self.goal_reached = true;
+            self.to_see.clear();
+            return;
+        }
+        for s in (self.successor_fn)(node) {
+            self.push(s);
+        }
+    }
+
+    fn frontier_size(&self) -> Option<usize> {
+        Some(self.to_see.len())
+    }
+}
+
+/// Creates an A* Search traversal iterator starting from the given node.
+///
+/// This function initializes a lazy iterator that explores the tree by always selecting the
+/// node with the lowest `path_cost + heuristic`. The iterator stops right after the first leaf
+/// node is yielded, because with an admissible heuristic that leaf is the optimal one.
+///
+/// # Parameters
+/// - `start`: The root node from which to begin the traversal.
+/// - `successor_fn`: A function that, given a node, returns an iterator over its successor nodes.
+/// - `leaf_check_fn`: A function that determines whether a given node is a leaf (goal) node.
+/// - `path_cost_fn`: A function that returns the cost of the path from the root to a node.
+/// - `heuristic_fn`: A function that returns an admissible estimate of the remaining cost of a node,
+///   or `None` if the node cannot lead to a valid leaf (and thus skipped).
+///
+/// # Returns
+/// An iterator that yields nodes reachable from the start node in A* order.
+/// The iterator is lazy and will only compute successors as needed.
+pub fn astar_reach<C, N, IN, FN, FL, FG, FH>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    path_cost_fn: FG,
+    heuristic_fn: FH,
+) -> Reachable<AStarContainer<C, N, FN, FL, FG, FH>>
+where
+    C: Ord + Copy + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FL: Fn(&N) -> bool,
+    FG: Fn(&N) -> C,
+    FH: Fn(&N) -> Option<C>,
+{
+    let container = AStarContainer::new(
+        start,
+        successor_fn,
+        leaf_check_fn,
+        path_cost_fn,
+        heuristic_fn,
+    );
+    Reachable::new(container)
+}
+
+/// Find the leaf node with the lowest cost by using A* Search
+///
+/// - `start` is the start node.
+/// - `successor_fn` returns a list of successors for a given node.
+/// - `leaf_check_fn` check if a node is leaf or not
+/// - `path_cost_fn` returns the path cost of a node; for a leaf node this is its final cost
+/// - `heuristic_fn` returns an admissible estimate of the remaining cost of a node
+/// - `max_ops` is the maximum number of search operations to perform
+/// - `time_limit` is the maximum duration allowed for the search operation
+///
+/// This function returns Some of a tuple of (cost, leaf node, proven) if found, otherwise returns None.
+/// `proven` is `true` when the search stopped because the optimal leaf was reached rather than
+/// because of `max_ops` or `time_limit`.
+pub fn astar<C, N, IN, FN, FL, FG, FH>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    path_cost_fn: FG,
+    heuristic_fn: FH,
+    max_ops: usize,
+    time_limit: Duration,
+) -> Option<(C, N, bool)>
+where
+    C: Ord + Copy + Add<Output = C>,
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    FL: Copy + Fn(&N) -> bool,
+    FG: Copy + Fn(&N) -> C,
+    FH: Fn(&N) -> Option<C>,
+{
+    let mut res = astar_reach(
+        start,
+        successor_fn,
+        leaf_check_fn,
+        path_cost_fn,
+        heuristic_fn,
+    );
+    let (cost, node) = find_best(
+        &mut res,
+        leaf_check_fn,
+        |n: &N| Some(path_cost_fn(n)),
+        max_ops,
+        time_limit,
+        |_, _| {},
+    )?;
+    Some((cost, node, res.container().is_goal_reached()))
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{super::dfs, astar};
+
+    // (row, col, path cost)
+    type Node = (usize, usize, u32);
+
+    #[test]
+    fn test_astar() {
+        let grid = [[1, 3, 1, 2], [1, 5, 1, 9], [4, 2, 1, 1], [7, 1, 8, 1]];
+        let goal = (grid.len() - 1, grid[0].len() - 1);
+
+        let successor_fn = |&(r, c, g): &Node| {
+            let mut children = vec![];
+            if r < goal.0 {
+                children.push((r + 1, c, g + grid[r + 1][c]));
+            }
+            if c < goal.1 {
+                children.push((r, c + 1, g + grid[r][c + 1]));
+            }
+            children
+        };
+        let leaf_check_fn = |&(r, c, _): &Node| (r, c) == goal;
+        let path_cost_fn = |&(_, _, g): &Node| g;
+        // every remaining step costs at least 1
+        let heuristic_fn = |&(r, c, _): &Node| Some(((goal.0 - r) + (goal.1 - c)) as u32);
+
+        let max_ops = usize::MAX;
+        let time_limit = Duration::from_secs(10);
+
+        let (cost, best_node, proven) = astar(
+            (0, 0, 0),
+            successor_fn,
+            leaf_check_fn,
+            path_cost_fn,
+            heuristic_fn,
+            max_ops,
+            time_limit,
+        )
+        .expect("A* should find a valid solution");
+
+        let (expected_cost, _) = dfs(
+            (0, 0, 0),
+            successor_fn,
+            leaf_check_fn,
+            |n: &Node| Some(path_cost_fn(n)),
+            max_ops,
+            time_limit,
+        )
+        .expect("DFS should find a valid solution");
+
+        assert!(proven);
+        assert_eq!(cost, expected_cost);
+        assert_eq!(best_node, (3, 3, expected_cost));
+    }
+}
@@ -1,20 +1,80 @@
 //! Branch and Bound Search
 
-use std::time::Duration;
+use std::{marker::PhantomData, ops::Sub, time::Duration};
+
+use crate::ToF64;
 
 use super::{
-    common::{NodeContainer, Reachable},
+    common::{NodeContainer, Reachable, SearchOutcome, find_best_until},
+    dominance::DominanceFilter,
     find_best,
+    incumbent::Incumbent,
+    observer::{ExpansionObserver, PruneReason},
+    stop::StopCondition,
 };
 
+/// A trait telling whether a node should be pruned, given its lower bound and the cost of the
+/// incumbent.
+///
+/// `()` prunes the nodes whose lower bound is not strictly lower than the incumbent, and
+/// [`AbsoluteTolerance`] prunes the nodes that cannot improve on it by more than an epsilon.
+pub trait Tolerance<C> {
+    /// Returns `true` if a node with the given lower bound cannot improve on the incumbent.
+    fn is_pruned(&self, lower_bound: C, best_cost: C) -> bool;
+}
+
+impl<C: Ord> Tolerance<C> for () {
+    fn is_pruned(&self, lower_bound: C, best_cost: C) -> bool {
+        best_cost <= lower_bound
+    }
+}
+
+/// A tolerance pruning the nodes whose lower bound is within an epsilon of the incumbent, i.e.
+/// `best cost - lower bound <= epsilon`.
+///
+/// The epsilon has the type of the difference of two costs, e.g. the plain objective for
+/// [`Maximize`](crate::Maximize) costs. This avoids expanding nodes which can only improve on the incumbent by a rounding error when
+/// the costs are floating-point numbers, e.g. [`NotNan`](crate::NotNan). The epsilon must not be
+/// negative. Since pruned nodes may hold leaves better than the incumbent by less than the
+/// epsilon, exhausting the search only proves that the best leaf is within the epsilon of the
+/// optimum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AbsoluteTolerance<E>(pub E);
+
+impl<C, E> Tolerance<C> for AbsoluteTolerance<E>
+where
+    C: Ord + Copy + Sub<Output = E>,
+    E: Ord,
+{
+    fn is_pruned(&self, lower_bound: C, best_cost: C) -> bool {
+        // the difference is only taken when positive, so that unsigned costs do not underflow
+        best_cost <= lower_bound || best_cost - lower_bound <= self.0
+    }
+}
+
 /// A container for Branch-and-Bound traversal.
-pub struct BranchAndBoundContainer<C, N, FN, FL, FC, FC2> {
+///
+/// Successors are checked against the dominance filter `D` before being pushed, and dominated
+/// successors are discarded. The default filter `()` never discards any node.
+/// Expansions and pruned nodes are reported to the observer `O`, which ignores them by default.
+/// The cost of the best leaf found so far is kept in the incumbent `B`, local to the container by
+/// default, and nodes whose lower bound is not better than it are pruned. The tolerance `T` tells
+/// when a lower bound is close enough to the incumbent for the node to be pruned, which by default
+/// is when it is not strictly lower.
+pub struct BranchAndBoundContainer<C, N, FN, FL, FC, FC2, D = (), O = (), B = Option<C>, T = ()> {
     to_see: Vec<N>,
     successor_fn: FN,
     leaf_check_fn: FL,
     cost_fn: FC,
     lower_bound_fn: FC2,
-    current_best_cost: Option<C>,
+    incumbent: B,
+    dominance: D,
+    observer: O,
+    tolerance: T,
+    /// The lowest lower bound among the nodes pruned although it was better than the incumbent,
+    /// which only happens with a tolerance.
+    pruned_lower_bound: Option<C>,
+    _cost: PhantomData<C>,
 }
 
 impl<C, N, IN, FN, FL, FC, FC2> BranchAndBoundContainer<C, N, FN, FL, FC, FC2>
@@ -40,12 +100,209 @@ where
             leaf_check_fn,
             cost_fn,
             lower_bound_fn,
-            current_best_cost: None,
+            incumbent: None,
+            dominance: (),
+            observer: (),
+            tolerance: (),
+            pruned_lower_bound: None,
+            _cost: PhantomData,
+        }
+    }
+
+    /// Creates a new `BranchAndBoundContainer` without any node to visit, e.g. for a worker of
+    /// [`pbbs_solve_with`](super::pbbs_solve_with) which only visits nodes stolen from the others.
+    pub fn empty(successor_fn: FN, leaf_check_fn: FL, cost_fn: FC, lower_bound_fn: FC2) -> Self {
+        Self {
+            to_see: vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            incumbent: None,
+            dominance: (),
+            observer: (),
+            tolerance: (),
+            pruned_lower_bound: None,
+            _cost: PhantomData,
         }
     }
 }
 
-impl<C, N, FN, FL, FC, FC2, IN> NodeContainer for BranchAndBoundContainer<C, N, FN, FL, FC, FC2>
+impl<C, N, FN, FL, FC, FC2, D, O, B, T> BranchAndBoundContainer<C, N, FN, FL, FC, FC2, D, O, B, T> {
+    /// Sets the dominance filter consulted before pushing successors.
+    pub fn with_dominance<D2>(
+        self,
+        dominance: D2,
+    ) -> BranchAndBoundContainer<C, N, FN, FL, FC, FC2, D2, O, B, T>
+    where
+        D2: DominanceFilter<N>,
+    {
+        BranchAndBoundContainer {
+            to_see: self.to_see,
+            successor_fn: self.successor_fn,
+            leaf_check_fn: self.leaf_check_fn,
+            cost_fn: self.cost_fn,
+            lower_bound_fn: self.lower_bound_fn,
+            incumbent: self.incumbent,
+            dominance,
+            observer: self.observer,
+            tolerance: self.tolerance,
+            pruned_lower_bound: self.pruned_lower_bound,
+            _cost: PhantomData,
+        }
+    }
+
+    /// Sets the observer notified of the expanded and pruned nodes.
+    pub fn with_observer<O2>(
+        self,
+        observer: O2,
+    ) -> BranchAndBoundContainer<C, N, FN, FL, FC, FC2, D, O2, B, T>
+    where
+        O2: ExpansionObserver<N>,
+    {
+        BranchAndBoundContainer {
+            to_see: self.to_see,
+            successor_fn: self.successor_fn,
+            leaf_check_fn: self.leaf_check_fn,
+            cost_fn: self.cost_fn,
+            lower_bound_fn: self.lower_bound_fn,
+            incumbent: self.incumbent,
+            dominance: self.dominance,
+            observer,
+            tolerance: self.tolerance,
+            pruned_lower_bound: self.pruned_lower_bound,
+            _cost: PhantomData,
+        }
+    }
+
+    /// Sets the incumbent read before expanding a node and offered the cost of every leaf found,
+    /// e.g. a [`SharedIncumbent`](super::SharedIncumbent) shared with other searches.
+    pub fn with_incumbent<B2>(
+        self,
+        incumbent: B2,
+    ) -> BranchAndBoundContainer<C, N, FN, FL, FC, FC2, D, O, B2, T>
+    where
+        B2: Incumbent<C>,
+    {
+        BranchAndBoundContainer {
+            to_see: self.to_see,
+            successor_fn: self.successor_fn,
+            leaf_check_fn: self.leaf_check_fn,
+            cost_fn: self.cost_fn,
+            lower_bound_fn: self.lower_bound_fn,
+            incumbent,
+            dominance: self.dominance,
+            observer: self.observer,
+            tolerance: self.tolerance,
+            pruned_lower_bound: self.pruned_lower_bound,
+            _cost: PhantomData,
+        }
+    }
+
+    /// Sets the tolerance used to compare lower bounds with the incumbent, e.g.
+    /// [`AbsoluteTolerance`] to prune nodes that cannot improve on it by more than an epsilon.
+    pub fn with_tolerance<T2>(
+        self,
+        tolerance: T2,
+    ) -> BranchAndBoundContainer<C, N, FN, FL, FC, FC2, D, O, B, T2>
+    where
+        T2: Tolerance<C>,
+    {
+        BranchAndBoundContainer {
+            to_see: self.to_see,
+            successor_fn: self.successor_fn,
+            leaf_check_fn: self.leaf_check_fn,
+            cost_fn: self.cost_fn,
+            lower_bound_fn: self.lower_bound_fn,
+            incumbent: self.incumbent,
+            dominance: self.dominance,
+            observer: self.observer,
+            tolerance,
+            pruned_lower_bound: self.pruned_lower_bound,
+            _cost: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the dominance filter.
+    pub fn dominance(&self) -> &D {
+        &self.dominance
+    }
+
+    /// Returns a reference to the observer.
+    pub fn observer(&self) -> &O {
+        &self.observer
+    }
+
+    /// Returns a reference to the incumbent.
+    pub fn incumbent(&self) -> &B {
+        &self.incumbent
+    }
+
+    /// Returns `true` if there is no node left to visit, which means the best leaf found is optimal.
+    ///
+    /// With a tolerance such as [`AbsoluteTolerance`], nodes that could still improve on the best
+    /// leaf by less than the tolerance have been pruned, so exhausting the search only proves that
+    /// the best leaf is within the tolerance of the optimum. [`lower_bound`](Self::lower_bound)
+    /// then reports the bound actually proven.
+    pub fn is_exhausted(&self) -> bool {
+        self.to_see.is_empty()
+    }
+
+    /// Removes the node waiting the longest, i.e. the shallowest one, for another worker.
+    pub(super) fn steal(&mut self) -> Option<N> {
+        (!self.to_see.is_empty()).then(|| self.to_see.remove(0))
+    }
+
+    /// Pushes back a node popped but not visited.
+    pub(super) fn push(&mut self, node: N) {
+        self.to_see.push(node);
+    }
+}
+
+impl<C, N, FN, FL, FC, FC2, D, O, B, T> BranchAndBoundContainer<C, N, FN, FL, FC, FC2, D, O, B, T>
+where
+    C: Ord + Copy,
+    FC2: Fn(&N) -> Option<C>,
+    B: Incumbent<C>,
+{
+    /// Seeds the incumbent with an upper bound, e.g. the cost of a heuristic solution, so that
+    /// only nodes whose lower bound is better than it are expanded.
+    ///
+    /// Leaves are not compared with the upper bound, so the traversal may still yield leaves
+    /// which are not better than it.
+    pub fn with_upper_bound(mut self, upper_bound: C) -> Self {
+        self.incumbent.offer(upper_bound);
+        self
+    }
+
+    /// Returns the cost of the best leaf found so far, or the upper bound it was seeded with.
+    pub fn best_cost(&self) -> Option<C> {
+        self.incumbent.best_cost()
+    }
+
+    /// Returns a lower bound on the cost of every leaf, including those not visited yet.
+    ///
+    /// This is the lowest lower bound among the nodes waiting to be visited, capped by the cost of
+    /// the best leaf found so far and, with a tolerance, by the lowest lower bound among the nodes
+    /// pruned because they could not improve on it by more than the tolerance, e.g. at least
+    /// `best cost - epsilon` with [`AbsoluteTolerance`]. It is computed by evaluating the lower
+    /// bound of every waiting node, so it is meant to be called once the search has stopped.
+    pub fn lower_bound(&self) -> Option<C> {
+        let frontier = self
+            .to_see
+            .iter()
+            .filter_map(|n| (self.lower_bound_fn)(n))
+            .chain(self.pruned_lower_bound)
+            .min();
+        match (frontier, self.best_cost()) {
+            (Some(lb), Some(c)) => Some(lb.min(c)),
+            (lb, c) => lb.or(c),
+        }
+    }
+}
+
+impl<C, N, FN, FL, FC, FC2, IN, D, O, B, T> NodeContainer
+    for BranchAndBoundContainer<C, N, FN, FL, FC, FC2, D, O, B, T>
 where
     C: Ord + Copy,
     FN: FnMut(&N) -> IN,
@@ -53,6 +310,10 @@ where
     FL: Fn(&N) -> bool,
     FC: Fn(&N) -> Option<C>,
     FC2: Fn(&N) -> Option<C>,
+    D: DominanceFilter<N>,
+    O: ExpansionObserver<N>,
+    B: Incumbent<C>,
+    T: Tolerance<C>,
 {
     type Node = N;
 
@@ -62,19 +323,108 @@ where
 
     fn expand_and_push(&mut self, node: &Self::Node) {
         if (self.leaf_check_fn)(node) {
-            if let Some(cost) = (self.cost_fn)(node)
-                && self.current_best_cost.is_none_or(|c| c > cost)
-            {
-                self.current_best_cost = Some(cost);
+            if let Some(cost) = (self.cost_fn)(node) {
+                self.incumbent.offer(cost);
             }
-        } else if let Some(lb) = (self.lower_bound_fn)(node)
-            && self.current_best_cost.is_none_or(|c| c > lb)
-        {
-            for s in (self.successor_fn)(node) {
-                self.to_see.push(s);
+            return;
+        }
+
+        match (self.lower_bound_fn)(node) {
+            None => self.observer.on_prune(node, PruneReason::Infeasible),
+            Some(lb) => {
+                if let Some(c) = self.incumbent.best_cost()
+                    && self.tolerance.is_pruned(lb, c)
+                {
+                    // the leaves below a node pruned thanks to the tolerance may beat the incumbent
+                    if lb < c {
+                        self.pruned_lower_bound =
+                            Some(self.pruned_lower_bound.map_or(lb, |b| b.min(lb)));
+                    }
+                    self.observer.on_prune(node, PruneReason::Bound);
+                    return;
+                }
+                let mut n_children = 0;
+                for s in (self.successor_fn)(node) {
+                    n_children += 1;
+                    if self.dominance.is_dominated(&s) {
+                        self.observer.on_prune(&s, PruneReason::Dominance);
+                    } else {
+                        self.to_see.push(s);
+                    }
+                }
+                self.observer.on_expand(node, n_children);
             }
         }
     }
+
+    fn frontier_size(&self) -> Option<usize> {
+        Some(self.to_see.len())
+    }
+}
+
+/// The result of a Branch-and-Bound search, with the information needed to tell whether the best
+/// leaf found is optimal or how far from optimal it can be.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchAndBoundResult<C, N> {
+    /// The best leaf found (the incumbent) and its cost, if any.
+    pub best: Option<(C, N)>,
+    /// A lower bound on the cost of every leaf, including those not visited, if known.
+    pub lower_bound: Option<C>,
+    /// Whether the whole search space has been explored (or pruned).
+    pub exhausted: bool,
+}
+
+impl<C: Ord + Copy, N> BranchAndBoundResult<C, N> {
+    /// Returns the cost of the best leaf found, if any.
+    pub fn best_cost(&self) -> Option<C> {
+        self.best.as_ref().map(|(c, _)| *c)
+    }
+
+    /// Returns `true` if the best leaf found is proven to be optimal, i.e. the lower bound reached
+    /// the cost of the best leaf, or the search space was exhausted when no lower bound is known.
+    ///
+    /// A search pruning with a tolerance may exhaust the search space without proving optimality,
+    /// its lower bound then tells how far from optimal the best leaf can be.
+    pub fn is_optimal(&self) -> bool {
+        match self.best_cost() {
+            Some(cost) => self.lower_bound.map_or(self.exhausted, |lb| lb >= cost),
+            None => false,
+        }
+    }
+
+    /// Returns `true` if the search space was exhausted without finding any leaf with a cost.
+    pub fn is_infeasible(&self) -> bool {
+        self.exhausted && self.best.is_none()
+    }
+}
+
+impl<C: Ord + Copy + Sub, N> BranchAndBoundResult<C, N> {
+    /// Returns the absolute optimality gap, `best cost - lower bound`.
+    ///
+    /// Returns `None` if no leaf has been found or no lower bound is known.
+    pub fn absolute_gap(&self) -> Option<C::Output> {
+        let cost = self.best_cost()?;
+        let lb = self.lower_bound?;
+        Some(cost - lb.min(cost))
+    }
+}
+
+impl<C: Ord + Copy + ToF64, N> BranchAndBoundResult<C, N> {
+    /// Returns the relative optimality gap, `(best cost - lower bound) / |best cost|`,
+    /// e.g. `0.03` when the best leaf is proven to be within 3% of the optimum.
+    /// The gap is infinite when the best cost is zero but the lower bound is below it.
+    /// Costs are converted with [`ToF64`], so any primitive integer cost is supported.
+    ///
+    /// Returns `None` if no leaf has been found or no lower bound is known.
+    pub fn relative_gap(&self) -> Option<f64> {
+        let cost = self.best_cost()?;
+        let lb = self.lower_bound?.min(cost);
+        if lb == cost {
+            return Some(0.0);
+        }
+        let (cost, lb) = (cost.to_f64(), lb.to_f64());
+        Some((cost - lb) / cost.abs())
+    }
 }
 
 /// Creates a Branch-and-Bound traversal iterator starting from the given node.
@@ -154,11 +504,278 @@ where
     )
 }
 
+/// Find the leaf node with the lowest cost until the given stop condition is met by using Branch and Bound
+///
+/// - `start` is the start node.
+/// - `successor_fn` returns a list of successors for a given node.
+/// - `leaf_check_fn` check if a node is leaf or not
+/// - `cost_fn` returns the final cost of a leaf node
+/// - `lower_bound_fn` returns the lower bound of a given node to decide whether to search deeper or not
+/// - `stop_condition` decides when to stop the search, e.g. `Any(MaxOps(1000), TargetCost(0))`
+///
+/// This function returns a [`SearchOutcome`] holding the best leaf found, if any, and the reason why the search stopped
+pub fn bbs_until<C, N, IN, FN, FL, FC, FC2, S>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+    stop_condition: S,
+) -> SearchOutcome<C, N>
+where
+    C: Ord + Copy,
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    FC: Copy + Fn(&N) -> Option<C>,
+    FL: Copy + Fn(&N) -> bool,
+    FC2: Fn(&N) -> Option<C>,
+    S: StopCondition<C>,
+{
+    let mut res = bbs_reach(start, successor_fn, leaf_check_fn, cost_fn, lower_bound_fn);
+    find_best_until(&mut res, leaf_check_fn, cost_fn, stop_condition, |_, _| {})
+}
+
+/// Find the leaf node with the lowest cost by using Branch and Bound, reporting the proof status
+///
+/// - `start` is the start node.
+/// - `successor_fn` returns a list of successors for a given node.
+/// - `leaf_check_fn` check if a node is leaf or not
+/// - `cost_fn` returns the final cost of a leaf node
+/// - `lower_bound_fn` returns the lower bound of a given node to decide whether to search deeper or not
+/// - `max_ops` is the maximum number of search operations to perform
+/// - `time_limit` is the maximum duration allowed for the search operation
+///
+/// Unlike [`bbs`], this function returns a [`BranchAndBoundResult`] telling whether the search was
+/// exhausted before `max_ops` or `time_limit` stopped it, and the optimality gap otherwise.
+pub fn bbs_solve<C, N, IN, FN, FL, FC, FC2>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+    max_ops: usize,
+    time_limit: Duration,
+) -> BranchAndBoundResult<C, N>
+where
+    C: Ord + Copy,
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    FC: Copy + Fn(&N) -> Option<C>,
+    FL: Copy + Fn(&N) -> bool,
+    FC2: Fn(&N) -> Option<C>,
+{
+    let mut res = bbs_reach(start, successor_fn, leaf_check_fn, cost_fn, lower_bound_fn);
+    let best = find_best(
+        &mut res,
+        leaf_check_fn,
+        cost_fn,
+        max_ops,
+        time_limit,
+        |_, _| {},
+    );
+    let container = res.container();
+    BranchAndBoundResult {
+        best,
+        lower_bound: container.lower_bound(),
+        exhausted: container.is_exhausted(),
+    }
+}
+
+/// Creates a Branch-and-Bound traversal iterator with dominance pruning starting from the given node.
+///
+/// This function works like [`bbs_reach`], but each successor is checked against the `dominance`
+/// filter before being pushed, and successors dominated by a node seen before are discarded.
+/// A [`DominanceStore`](super::DominanceStore) is a filter comparing the labels of nodes with the
+/// same remaining subproblem.
+///
+/// # Parameters
+/// - `start`: The root node from which to begin the traversal.
+/// - `successor_fn`: A function that, given a node, returns an iterator over its successor nodes.
+/// - `leaf_check_fn`: A function that determines whether a given node is a leaf (terminal) node.
+/// - `cost_fn`: A function that computes the cost of a leaf node, returning `Some(cost)` if the cost
+///   can be determined, or `None` otherwise.
+/// - `lower_bound_fn`: A function that provides a lower bound on the cost for a given node, used
+///   for pruning suboptimal branches.
+/// - `dominance`: The filter that decides whether a successor is dominated.
+///
+/// # Returns
+/// An iterator that yields nodes reachable from the start node in Branch-and-Bound order.
+/// The iterator is lazy and will only compute successors as needed.
+pub fn bbs_dominance_reach<C, N, IN, FN, FL, FC, FC2, D>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+    dominance: D,
+) -> Reachable<BranchAndBoundContainer<C, N, FN, FL, FC, FC2, D>>
+where
+    C: Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FC: Fn(&N) -> Option<C>,
+    FL: Fn(&N) -> bool,
+    FC2: Fn(&N) -> Option<C>,
+    D: DominanceFilter<N>,
+{
+    let container =
+        BranchAndBoundContainer::new(start, successor_fn, leaf_check_fn, cost_fn, lower_bound_fn)
+            .with_dominance(dominance);
+    Reachable::new(container)
+}
+
+/// Find the leaf node with the lowest cost by using Branch and Bound with dominance pruning
+///
+/// - `start` is the start node.
+/// - `successor_fn` returns a list of successors for a given node.
+/// - `leaf_check_fn` check if a node is leaf or not
+/// - `cost_fn` returns the final cost of a leaf node
+/// - `lower_bound_fn` returns the lower bound of a given node to decide whether to search deeper or not
+/// - `dominance` decides whether a successor is dominated by a node seen before
+/// - `max_ops` is the maximum number of search operations to perform
+/// - `time_limit` is the maximum duration allowed for the search operation
+///
+/// This function returns Some of a tuple of (cost, leaf node) if found, otherwise returns None
+#[allow(clippy::too_many_arguments)]
+pub fn bbs_dominance<C, N, IN, FN, FL, FC, FC2, D>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+    dominance: D,
+    max_ops: usize,
+    time_limit: Duration,
+) -> Option<(C, N)>
+where
+    C: Ord + Copy,
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    FC: Copy + Fn(&N) -> Option<C>,
+    FL: Copy + Fn(&N) -> bool,
+    FC2: Fn(&N) -> Option<C>,
+    D: DominanceFilter<N>,
+{
+    let mut res = bbs_dominance_reach(
+        start,
+        successor_fn,
+        leaf_check_fn,
+        cost_fn,
+        lower_bound_fn,
+        dominance,
+    );
+    find_best(
+        &mut res,
+        leaf_check_fn,
+        cost_fn,
+        max_ops,
+        time_limit,
+        |_, _| {},
+    )
+}
+
+/// Creates a Branch-and-Bound traversal iterator reading and updating the given incumbent.
+///
+/// This function works like [`bbs_reach`], but the cost of the best leaf found so far is kept in
+/// `incumbent` instead of inside the container. Passing `Some(upper_bound)` seeds the search with
+/// the cost of a known solution, and a [`SharedIncumbent`](super::SharedIncumbent) lets several
+/// searches prune with the best leaf found by any of them.
+///
+/// # Parameters
+/// - `start`: The root node from which to begin the traversal.
+/// - `successor_fn`: A function that, given a node, returns an iterator over its successor nodes.
+/// - `leaf_check_fn`: A function that determines whether a given node is a leaf (terminal) node.
+/// - `cost_fn`: A function that computes the cost of a leaf node, returning `Some(cost)` if the cost
+///   can be determined, or `None` otherwise.
+/// - `lower_bound_fn`: A function that provides a lower bound on the cost for a given node, used
+///   for pruning suboptimal branches.
+/// - `incumbent`: The incumbent read before expanding a node and offered the cost of every leaf.
+///
+/// # Returns
+/// An iterator that yields nodes reachable from the start node in Branch-and-Bound order.
+/// The iterator is lazy and will only compute successors as needed.
+#[allow(clippy::type_complexity)]
+pub fn bbs_incumbent_reach<C, N, IN, FN, FL, FC, FC2, B>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+    incumbent: B,
+) -> Reachable<BranchAndBoundContainer<C, N, FN, FL, FC, FC2, (), (), B>>
+where
+    C: Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FC: Fn(&N) -> Option<C>,
+    FL: Fn(&N) -> bool,
+    FC2: Fn(&N) -> Option<C>,
+    B: Incumbent<C>,
+{
+    let container =
+        BranchAndBoundContainer::new(start, successor_fn, leaf_check_fn, cost_fn, lower_bound_fn)
+            .with_incumbent(incumbent);
+    Reachable::new(container)
+}
+
+/// Find the leaf node with the lowest cost by using Branch and Bound with the given incumbent
+///
+/// - `start` is the start node.
+/// - `successor_fn` returns a list of successors for a given node.
+/// - `leaf_check_fn` check if a node is leaf or not
+/// - `cost_fn` returns the final cost of a leaf node
+/// - `lower_bound_fn` returns the lower bound of a given node to decide whether to search deeper or not
+/// - `incumbent` holds the cost of the best leaf found so far, possibly seeded with an upper bound or shared
+/// - `max_ops` is the maximum number of search operations to perform
+/// - `time_limit` is the maximum duration allowed for the search operation
+///
+/// This function returns Some of a tuple of (cost, leaf node) if found, otherwise returns None.
+/// Only nodes whose lower bound cannot beat the incumbent are pruned, leaves are not compared with it: the
+/// result may be no better than the initial upper bound, e.g. a leaf below a node whose lower bound beat it,
+/// so its cost has to be compared with the upper bound to tell whether it improves on it.
+#[allow(clippy::too_many_arguments)]
+pub fn bbs_incumbent<C, N, IN, FN, FL, FC, FC2, B>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+    incumbent: B,
+    max_ops: usize,
+    time_limit: Duration,
+) -> Option<(C, N)>
+where
+    C: Ord + Copy,
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    FC: Copy + Fn(&N) -> Option<C>,
+    FL: Copy + Fn(&N) -> bool,
+    FC2: Fn(&N) -> Option<C>,
+    B: Incumbent<C>,
+{
+    let mut res = bbs_incumbent_reach(
+        start,
+        successor_fn,
+        leaf_check_fn,
+        cost_fn,
+        lower_bound_fn,
+        incumbent,
+    );
+    find_best(
+        &mut res,
+        leaf_check_fn,
+        cost_fn,
+        max_ops,
+        time_limit,
+        |_, _| {},
+    )
+}
+
 #[cfg(test)]
 mod test {
     use std::time::Duration;
 
-    use super::bbs;
+    use super::{bbs, bbs_solve};
     type Node = Vec<bool>;
     #[test]
     fn test_bbs() {
@@ -232,5 +849,35 @@ mod test {
 
         assert_eq!(cost, 120);
         assert_eq!(best_node, vec![true, true, false, false, false]);
+
+        let res = bbs_solve(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            max_ops,
+            time_limit,
+        );
+        assert!(res.exhausted && res.is_optimal());
+        assert_eq!(res.best_cost(), Some(u32::MAX - 120));
+        assert_eq!(res.absolute_gap(), Some(0));
+        assert_eq!(res.relative_gap(), Some(0.0));
+
+        // stopped right after the first leaf, the empty knapsack, while the item of profit 100
+        // and all the others can still be taken
+        let res = bbs_solve(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            6,
+            time_limit,
+        );
+        assert!(!res.exhausted && !res.is_optimal());
+        assert_eq!(res.best_cost(), Some(u32::MAX));
+        assert_eq!(res.absolute_gap(), Some(137));
+        assert_eq!(res.relative_gap(), Some(137.0 / f64::from(u32::MAX)));
     }
 }
@@ -0,0 +1,430 @@
+//! Best-First Branch and Bound Search
+
+use std::{cmp::Reverse, collections::BinaryHeap, time::Duration};
+
+use crate::utils::ScoredItem;
+
+use super::{
+    bbs::BranchAndBoundResult,
+    common::{NodeContainer, Reachable},
+    find_best,
+};
+
+/// A container for Best-First Branch-and-Bound traversal.
+///
+/// Nodes are kept in a heap keyed on their lower bound, so the node with the lowest lower bound is
+/// expanded first, and ties are broken in favor of the most recently pushed node. The lower bound
+/// of a node is checked against the best leaf found so far both when it is pushed and when it is
+/// popped, so nodes that became useless while waiting in the heap are never expanded.
+///
+/// In the hybrid mode (`dive_first`), the container dives depth-first, trying the child with the
+/// lowest lower bound first, until the first leaf with a cost is found, and then proceeds
+/// best-first.
+pub struct BestFirstBranchAndBoundContainer<C: Ord, N, FN, FL, FC, FC2> {
+    to_see: BinaryHeap<ScoredItem<(Reverse<C>, usize), N>>,
+    dive: Vec<(C, N)>,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+    current_best_cost: Option<C>,
+    dive_first: bool,
+    seq: usize,
+}
+
+impl<C, N, IN, FN, FL, FC, FC2> BestFirstBranchAndBoundContainer<C, N, FN, FL, FC, FC2>
+where
+    C: Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+    FC2: Fn(&N) -> Option<C>,
+{
+    /// Creates a new `BestFirstBranchAndBoundContainer` with the given parameters.
+    pub fn new(
+        start: N,
+        successor_fn: FN,
+        leaf_check_fn: FL,
+        cost_fn: FC,
+        lower_bound_fn: FC2,
+        dive_first: bool,
+    ) -> Self {
+        let mut container = Self {
+            to_see: BinaryHeap::new(),
+            dive: vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            current_best_cost: None,
+            dive_first,
+            seq: 0,
+        };
+        if let Some(lb) = (container.lower_bound_fn)(&start) {
+            container.push(lb, start);
+        }
+        container
+    }
+
+    /// Returns the cost of the best leaf found so far.
+    pub fn best_cost(&self) -> Option<C> {
+        self.current_best_cost
+    }
+
+    /// Returns `true` if there is no node left to visit, which means the best leaf found is optimal.
+    pub fn is_exhausted(&self) -> bool {
+        self.to_see.is_empty() && self.dive.is_empty()
+    }
+
+    /// Returns the global lower bound, i.e. a lower bound on the cost of every leaf, including
+    /// those not visited yet.
+    ///
+    /// This is the lowest lower bound among the nodes waiting to be expanded, capped by the cost
+    /// of the best leaf found so far. When no node is waiting, it is the cost of the best leaf,
+    /// which is then proven to be optimal. Returns `None` if there is no node left and no leaf
+    /// has been found.
+    pub fn global_lower_bound(&self) -> Option<C> {
+        let frontier = self
+            .to_see
+            .peek()
+            .map(|item| item.score().0.0)
+            .into_iter()
+            .chain(self.dive.iter().map(|(lb, _)| *lb))
+            .min();
+        match (frontier, self.current_best_cost) {
+            (Some(lb), Some(c)) => Some(lb.min(c)),
+            (lb, c) => lb.or(c),
+        }
+    }
+
+    fn is_diving(&self) -> bool {
+        self.dive_first && self.current_best_cost.is_none()
+    }
+
+    fn is_promising(&self, lb: C) -> bool {
+        self.current_best_cost.is_none_or(|c| c > lb)
+    }
+
+    fn push(&mut self, lb: C, node: N) {
+        self.seq += 1;
+        self.to_see
+            .push(ScoredItem::from(((Reverse(lb), self.seq), node)));
+    }
+}
+
+impl<C, N, IN, FN, FL, FC, FC2> NodeContainer
+    for BestFirstBranchAndBoundContainer<C, N, FN, FL, FC, FC2>
+where
+    C: Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+    FC2: Fn(&N) -> Option<C>,
+{
+    type Node = N;
+
+    fn pop(&mut self) -> Option<Self::Node> {
+        while let Some((lb, node)) = self.dive.pop() {
+            if self.is_promising(lb) {
+                return Some(node);
+            }
+        }
+        while let Some(item) = self.to_see.pop() {
+            let ((Reverse(lb), _), node) = item.into_inner();
+            if self.is_promising(lb) {
+                return Some(node);
+            }
+        }
+        None
+    }
+
+    fn expand_and_push(&mut self, node: &Self::Node) {
+        if (self.leaf_check_fn)(node) {
+            if let Some(cost) = (self.cost_fn)(node)
+                && self.is_promising(cost)
+            {
+                self.current_best_cost = Some(cost);
+                // the dive is over, the remaining nodes are explored best-first
+                for (lb, n) in std::mem::take(&mut self.dive) {
+                    self.push(lb, n);
+                }
+            }
+            return;
+        }
+
+        let diving = self.is_diving();
+        let mut children = vec![];
+        for s in (self.successor_fn)(node) {
+            let Some(lb) = (self.lower_bound_fn)(&s) else {
+                continue;
+            };
+            if !self.is_promising(lb) {
+                continue;
+            }
+            if diving {
+                children.push((lb, s));
+            } else {
+                self.push(lb, s);
+            }
+        }
+        // the child with the lowest lower bound is popped first
+        children.sort_by_key(|(lb, _)| Reverse(*lb));
+        self.dive.extend(children);
+    }
+
+    fn frontier_size(&self) -> Option<usize> {
+        Some(self.to_see.len() + self.dive.len())
+    }
+}
+
+/// Creates a Best-First Branch-and-Bound traversal iterator starting from the given node.
+///
+/// This function initializes a lazy iterator that always expands the node with the lowest lower
+/// bound, pruning nodes whose lower bound is not better than the best leaf found so far, both when
+/// they are generated and when they are about to be expanded. With `dive_first`, the traversal
+/// first dives depth-first to find an initial leaf quickly and then proceeds best-first.
+///
+/// # Parameters
+/// - `start`: The root node from which to begin the traversal.
+/// - `successor_fn`: A function that, given a node, returns an iterator over its successor nodes.
+/// - `leaf_check_fn`: A function that determines whether a given node is a leaf (terminal) node.
+/// - `cost_fn`: A function that computes the cost of a leaf node, returning `Some(cost)` if the cost
+///   can be determined, or `None` otherwise.
+/// - `lower_bound_fn`: A function that provides a lower bound on the cost for a given node, used
+///   for ordering the nodes and pruning suboptimal branches.
+/// - `dive_first`: Whether to dive depth-first until the first leaf is found.
+///
+/// # Returns
+/// An iterator that yields nodes reachable from the start node in best-first order of their lower
+/// bounds. The iterator is lazy and will only compute successors as needed.
+pub fn bfbb_reach<C, N, IN, FN, FL, FC, FC2>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+    dive_first: bool,
+) -> Reachable<BestFirstBranchAndBoundContainer<C, N, FN, FL, FC, FC2>>
+where
+    C: Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+    FC2: Fn(&N) -> Option<C>,
+{
+    let container = BestFirstBranchAndBoundContainer::new(
+        start,
+        successor_fn,
+        leaf_check_fn,
+        cost_fn,
+        lower_bound_fn,
+        dive_first,
+    );
+    Reachable::new(container)
+}
+
+/// Find the leaf node with the lowest cost by using Best-First Branch and Bound
+///
+/// - `start` is the start node.
+/// - `successor_fn` returns a list of successors for a given node.
+/// - `leaf_check_fn` check if a node is leaf or not
+/// - `cost_fn` returns the final cost of a leaf node
+/// - `lower_bound_fn` returns the lower bound of a given node, used to order and prune the nodes
+/// - `dive_first` decides whether to dive depth-first until the first leaf is found
+/// - `max_ops` is the maximum number of search operations to perform
+/// - `time_limit` is the maximum duration allowed for the search operation
+///
+/// This function returns Some of a tuple of (cost, leaf node) if found, otherwise returns None
+#[allow(clippy::too_many_arguments)]
+pub fn bfbb<C, N, IN, FN, FL, FC, FC2>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+    dive_first: bool,
+    max_ops: usize,
+    time_limit: Duration,
+) -> Option<(C, N)>
+where
+    C: Ord + Copy,
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    FC: Copy + Fn(&N) -> Option<C>,
+    FL: Copy + Fn(&N) -> bool,
+    FC2: Fn(&N) -> Option<C>,
+{
+    let mut res = bfbb_reach(
+        start,
+        successor_fn,
+        leaf_check_fn,
+        cost_fn,
+        lower_bound_fn,
+        dive_first,
+    );
+    find_best(
+        &mut res,
+        leaf_check_fn,
+        cost_fn,
+        max_ops,
+        time_limit,
+        |_, _| {},
+    )
+}
+
+/// Find the leaf node with the lowest cost by using Best-First Branch and Bound, reporting the proof status
+///
+/// - `start` is the start node.
+/// - `successor_fn` returns a list of successors for a given node.
+/// - `leaf_check_fn` check if a node is leaf or not
+/// - `cost_fn` returns the final cost of a leaf node
+/// - `lower_bound_fn` returns the lower bound of a given node, used to order and prune the nodes
+/// - `dive_first` decides whether to dive depth-first until the first leaf is found
+/// - `max_ops` is the maximum number of search operations to perform
+/// - `time_limit` is the maximum duration allowed for the search operation
+///
+/// Unlike [`bfbb`], this function returns a [`BranchAndBoundResult`] telling whether the search was
+/// exhausted before `max_ops` or `time_limit` stopped it, and the optimality gap otherwise.
+#[allow(clippy::too_many_arguments)]
+pub fn bfbb_solve<C, N, IN, FN, FL, FC, FC2>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+    dive_first: bool,
+    max_ops: usize,
+    time_limit: Duration,
+) -> BranchAndBoundResult<C, N>
+where
+    C: Ord + Copy,
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    FC: Copy + Fn(&N) -> Option<C>,
+    FL: Copy + Fn(&N) -> bool,
+    FC2: Fn(&N) -> Option<C>,
+{
+    let mut res = bfbb_reach(
+        start,
+        successor_fn,
+        leaf_check_fn,
+        cost_fn,
+        lower_bound_fn,
+        dive_first,
+    );
+    let best = find_best(
+        &mut res,
+        leaf_check_fn,
+        cost_fn,
+        max_ops,
+        time_limit,
+        |_, _| {},
+    );
+    let container = res.container();
+    BranchAndBoundResult {
+        best,
+        lower_bound: container.global_lower_bound(),
+        exhausted: container.is_exhausted(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{bfbb, bfbb_reach};
+    type Node = Vec<bool>;
+    #[test]
+    fn test_bfbb() {
+        let weights = [4, 2, 6, 3, 4];
+        let profits = [100, 20, 2, 5, 10];
+        let capacity = 8;
+        let total_items = weights.len();
+
+        let successor_fn = |n: &Node| {
+            if n.len() == total_items {
+                return vec![];
+            }
+
+            let total_weight: u32 = n
+                .iter()
+                .copied()
+                .enumerate()
+                .map(|(i, b)| if b { weights[i] } else { 0 })
+                .sum();
+
+            let mut children = vec![];
+
+            let next_idx = n.len();
+            if capacity >= total_weight + weights[next_idx] {
+                let mut c1 = n.clone();
+                c1.push(true);
+                children.push(c1);
+            }
+
+            let mut c2 = n.clone();
+            c2.push(false);
+            children.push(c2);
+
+            children
+        };
+
+        let total_profit = |n: &Node| {
+            let s: u32 = n
+                .iter()
+                .copied()
+                .enumerate()
+                .map(|(i, b)| if b { profits[i] } else { 0 })
+                .sum();
+            s
+        };
+
+        let lower_bound_fn = |n: &Node| {
+            let current_profit = total_profit(n);
+            let max_remained_profit: u32 = profits[n.len()..].iter().sum();
+            Some(u32::MAX - (current_profit + max_remained_profit))
+        };
+
+        let cost_fn = |n: &Node| Some(u32::MAX - total_profit(n));
+
+        let leaf_check_fn = |n: &Node| n.len() == total_items;
+
+        for dive_first in [false, true] {
+            let (cost, best_node) = bfbb(
+                vec![],
+                successor_fn,
+                leaf_check_fn,
+                cost_fn,
+                lower_bound_fn,
+                dive_first,
+                usize::MAX,
+                Duration::from_secs(10),
+            )
+            .expect("BFBB should find a valid solution");
+            let cost = u32::MAX - cost;
+
+            assert_eq!(cost, 120);
+            assert_eq!(best_node, vec![true, true, false, false, false]);
+        }
+
+        // the global lower bound never decreases and meets the optimum once exhausted
+        let mut res = bfbb_reach(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            false,
+        );
+        let mut bounds = vec![res.container().global_lower_bound()];
+        while res.next().is_some() {
+            bounds.push(res.container().global_lower_bound());
+        }
+        assert!(bounds.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(bounds.last(), Some(&Some(u32::MAX - 120)));
+        assert_eq!(res.container().best_cost(), Some(u32::MAX - 120));
+    }
+}
@@ -1,22 +1,35 @@
 //! Beam Search
 
 use std::{
+    cmp::Reverse,
     collections::{BinaryHeap, VecDeque},
     time::Duration,
 };
 
 use crate::utils::ScoredItem;
 
-use super::common::{NodeContainer, Reachable, find_best};
+use super::{
+    common::{NodeContainer, Reachable, SearchOutcome, find_best, find_best_until},
+    observer::{ExpansionObserver, PruneReason},
+    stop::StopCondition,
+};
 
 /// A container for Beam Search traversal.
-pub struct BeamContainer<N, FN, FP, P: Ord> {
+///
+/// Among nodes with the same priority, those generated first are kept first, so the result only
+/// depends on the order of the successors.
+/// Expansions and nodes discarded because of the branch factor or the beam width are reported to
+/// the observer `O`, which ignores them by default.
+pub struct BeamContainer<N, FN, FP, P: Ord, O = ()> {
     to_see: VecDeque<N>,
     successor_fn: FN,
     priority_fn: FP,
     branch_factor: usize,
     beam_width: usize,
-    pool: BinaryHeap<ScoredItem<P, N>>,
+    pool: BinaryHeap<ScoredItem<(P, Reverse<usize>), N>>,
+    seq: usize,
+    truncated: bool,
+    observer: O,
 }
 
 impl<N, IN, FN, FP, P> BeamContainer<N, FN, FP, P>
@@ -41,45 +54,108 @@ where
             branch_factor,
             beam_width,
             pool: BinaryHeap::new(),
+            seq: 0,
+            truncated: false,
+            observer: (),
         }
     }
+
+    /// Sets the observer notified of the expanded and discarded nodes.
+    pub fn with_observer<O>(self, observer: O) -> BeamContainer<N, FN, FP, P, O>
+    where
+        O: ExpansionObserver<N>,
+    {
+        BeamContainer {
+            to_see: self.to_see,
+            successor_fn: self.successor_fn,
+            priority_fn: self.priority_fn,
+            branch_factor: self.branch_factor,
+            beam_width: self.beam_width,
+            pool: self.pool,
+            seq: self.seq,
+            truncated: self.truncated,
+            observer,
+        }
+    }
+
+    /// Restarts the beam search from `start` with a new beam width.
+    pub(super) fn restart(&mut self, start: N, beam_width: usize) {
+        self.to_see.clear();
+        self.to_see.push_back(start);
+        self.pool.clear();
+        self.seq = 0;
+        self.beam_width = beam_width;
+        self.truncated = false;
+    }
 }
 
-impl<N, IN, FN, FP, P> NodeContainer for BeamContainer<N, FN, FP, P>
+impl<N, FN, FP, P: Ord, O> BeamContainer<N, FN, FP, P, O> {
+    /// Returns `true` if any depth level had more candidates than `beam_width`, i.e. some nodes
+    /// were discarded because of the beam width.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Returns a reference to the observer.
+    pub fn observer(&self) -> &O {
+        &self.observer
+    }
+}
+
+impl<N, IN, FN, FP, P, O> NodeContainer for BeamContainer<N, FN, FP, P, O>
 where
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = N>,
     FP: Fn(&N) -> Option<P>,
     P: Ord + Copy,
+    O: ExpansionObserver<N>,
 {
     type Node = N;
 
     fn pop(&mut self) -> Option<Self::Node> {
         if self.to_see.is_empty() {
+            if self.pool.len() > self.beam_width {
+                self.truncated = true;
+            }
             let max_iter = std::cmp::min(self.pool.len(), self.beam_width);
             for _ in 0..max_iter {
                 if let Some(node) = self.pool.pop() {
                     self.to_see.push_back(node.into_item());
                 }
             }
-            self.pool.clear();
+            for item in self.pool.drain() {
+                self.observer
+                    .on_prune(&item.into_item(), PruneReason::BeamWidth);
+            }
         }
         self.to_see.pop_front()
     }
 
     fn expand_and_push(&mut self, node: &Self::Node) {
-        let mut successors: Vec<_> = (self.successor_fn)(node)
-            .into_iter()
-            .filter_map(|n| {
-                let priority = (self.priority_fn)(&n)?;
-                Some((priority, n))
-            })
-            .collect();
-        successors.sort_by(|a, b| b.0.cmp(&a.0));
-        successors
-            .into_iter()
-            .take(self.branch_factor)
-            .for_each(|(score, n)| self.pool.push(ScoredItem::from((score, n))));
+        let mut n_children = 0;
+        let mut successors = vec![];
+        for n in (self.successor_fn)(node) {
+            n_children += 1;
+            match (self.priority_fn)(&n) {
+                Some(priority) => successors.push((priority, n)),
+                None => self.observer.on_prune(&n, PruneReason::Infeasible),
+            }
+        }
+        self.observer.on_expand(node, n_children);
+        successors.sort_by_key(|s| Reverse(s.0));
+        for (i, (score, n)) in successors.into_iter().enumerate() {
+            if i < self.branch_factor {
+                self.seq += 1;
+                self.pool
+                    .push(ScoredItem::from(((score, Reverse(self.seq)), n)));
+            } else {
+                self.observer.on_prune(&n, PruneReason::BranchFactor);
+            }
+        }
+    }
+
+    fn frontier_size(&self) -> Option<usize> {
+        Some(self.to_see.len() + self.pool.len())
     }
 }
 
@@ -162,6 +238,43 @@ where
     )
 }
 
+/// Find the leaf node with the lowest cost until the given stop condition is met by using Beam Search
+///
+/// - `start` is the start node.
+/// - `successor_fn` returns a list of successors for a given node.
+/// - `leaf_check_fn` checks if a node is a leaf or not
+/// - `cost_fn` returns the final cost of a leaf node
+/// - `priority_fn` returns the priority of a given node to sort and select k-best
+/// - `branch_factor` decides maximum number of branches from a node
+/// - `beam_width` decides maximum number of nodes at each depth.
+/// - `stop_condition` decides when to stop the search, e.g. `Any(MaxOps(1000), TargetCost(0))`
+///
+/// This function returns a [`SearchOutcome`] holding the best leaf found, if any, and the reason why the search stopped
+#[allow(clippy::too_many_arguments)]
+pub fn bms_until<N, IN, FN, FC, FP, C, P, FR, S>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FR,
+    cost_fn: FC,
+    priority_fn: FP,
+    branch_factor: usize,
+    beam_width: usize,
+    stop_condition: S,
+) -> SearchOutcome<C, N>
+where
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    FC: Fn(&N) -> Option<C>,
+    FP: Fn(&N) -> Option<P>,
+    C: Ord + Copy,
+    P: Ord + Copy,
+    FR: Fn(&N) -> bool,
+    S: StopCondition<C>,
+{
+    let mut res = bms_reach(start, successor_fn, priority_fn, branch_factor, beam_width);
+    find_best_until(&mut res, leaf_check_fn, cost_fn, stop_condition, |_, _| {})
+}
+
 #[cfg(test)]
 mod test {
     use std::cmp::Reverse;
@@ -0,0 +1,363 @@
+//! Beam Stack Search
+
+use std::{cmp::Reverse, collections::VecDeque, time::Duration};
+
+use super::{
+    common::{NodeContainer, Reachable},
+    find_best,
+};
+
+/// A container for Beam Stack traversal, a complete anytime variant of beam search.
+///
+/// Like beam search, the container descends level by level keeping at most `beam_width` nodes,
+/// selected by priority. Unlike beam search, the candidates that did not fit in the beam are not
+/// discarded: each level keeps its remaining candidates on a stack of layers, and once the search
+/// below a beam is finished, the next `beam_width` candidates of the deepest non-empty layer form
+/// the new beam. Nodes whose lower bound is not better than the best leaf found so far are pruned,
+/// so when the stack is empty the best leaf found is proven to be optimal. Nodes without a
+/// priority are kept and tried after the nodes of their level that have one.
+pub struct BeamStackContainer<C, N, FN, FL, FC, FC2, FP, P> {
+    to_see: VecDeque<N>,
+    layers: Vec<VecDeque<(C, N)>>,
+    next_layer: Vec<(Option<P>, C, N)>,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+    priority_fn: FP,
+    beam_width: usize,
+    current_best_cost: Option<C>,
+}
+
+impl<C, N, IN, FN, FL, FC, FC2, FP, P> BeamStackContainer<C, N, FN, FL, FC, FC2, FP, P>
+where
+    C: Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+    FC2: Fn(&N) -> Option<C>,
+    FP: Fn(&N) -> Option<P>,
+    P: Ord + Copy,
+{
+    /// Creates a new `BeamStackContainer` with the given parameters.
+    ///
+    /// Panics if `beam_width` is zero, since no node could ever be selected.
+    pub fn new(
+        start: N,
+        successor_fn: FN,
+        leaf_check_fn: FL,
+        cost_fn: FC,
+        lower_bound_fn: FC2,
+        priority_fn: FP,
+        beam_width: usize,
+    ) -> Self {
+        assert!(beam_width > 0, "beam_width must be positive");
+        Self {
+            to_see: vec![start].into(),
+            layers: vec![],
+            next_layer: vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            priority_fn,
+            beam_width,
+            current_best_cost: None,
+        }
+    }
+
+    /// Returns `true` if every node that could improve on the best leaf has been visited,
+    /// which means the best leaf found is optimal.
+    pub fn is_exhausted(&self) -> bool {
+        self.to_see.is_empty()
+            && self.next_layer.is_empty()
+            && self.layers.iter().all(|layer| layer.is_empty())
+    }
+
+    fn is_promising(&self, lb: C) -> bool {
+        self.current_best_cost.is_none_or(|c| c > lb)
+    }
+
+    fn next_beam(&mut self) {
+        if !self.next_layer.is_empty() {
+            let mut layer = std::mem::take(&mut self.next_layer);
+            // nodes without a priority compare as `None`, so they come last
+            layer.sort_by_key(|(priority, _, _)| Reverse(*priority));
+            self.layers
+                .push(layer.into_iter().map(|(_, lb, n)| (lb, n)).collect());
+        }
+
+        while let Some(layer) = self.layers.last_mut() {
+            while self.to_see.len() < self.beam_width
+                && let Some((lb, n)) = layer.pop_front()
+            {
+                if self.current_best_cost.is_none_or(|c| c > lb) {
+                    self.to_see.push_back(n);
+                }
+            }
+            if !self.to_see.is_empty() {
+                return;
+            }
+            self.layers.pop();
+        }
+    }
+}
+
+impl<C, N, IN, FN, FL, FC, FC2, FP, P> NodeContainer
+    for BeamStackContainer<C, N, FN, FL, FC, FC2, FP, P>
+where
+    C: Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+    FC2: Fn(&N) -> Option<C>,
+    FP: Fn(&N) -> Option<P>,
+    P: Ord + Copy,
+{
+    type Node = N;
+
+    fn pop(&mut self) -> Option<Self::Node> {
+        if self.to_see.is_empty() {
+            self.next_beam();
+        }
+        self.to_see.pop_front()
+    }
+
+    fn expand_and_push(&mut self, node: &Self::Node) {
+        if (self.leaf_check_fn)(node) {
+            if let Some(cost) = (self.cost_fn)(node)
+                && self.is_promising(cost)
+            {
+                self.current_best_cost = Some(cost);
+            }
+            return;
+        }
+
+        for s in (self.successor_fn)(node) {
+            let Some(lb) = (self.lower_bound_fn)(&s) else {
+                continue;
+            };
+            if !self.is_promising(lb) {
+                continue;
+            }
+            let priority = (self.priority_fn)(&s);
+            self.next_layer.push((priority, lb, s));
+        }
+    }
+
+    fn frontier_size(&self) -> Option<usize> {
+        Some(
+            self.to_see.len()
+                + self.next_layer.len()
+                + self.layers.iter().map(VecDeque::len).sum::<usize>(),
+        )
+    }
+}
+
+/// Creates a Beam Stack Search traversal iterator starting from the given node.
+///
+/// This function initializes a lazy iterator that behaves like beam search at first, yielding a
+/// first solution quickly, and then backtracks into the candidates that did not fit in the beam,
+/// `beam_width` nodes at a time, starting from the deepest level. Branches whose lower bound is not
+/// better than the best leaf found so far are pruned, so if the iterator runs to completion the
+/// best leaf it yielded is optimal.
+///
+/// # Parameters
+/// - `start`: The root node from which to begin the traversal.
+/// - `successor_fn`: A function that, given a node, returns an iterator over its successor nodes.
+/// - `leaf_check_fn`: A function that determines whether a given node is a leaf (terminal) node.
+/// - `cost_fn`: A function that computes the cost of a leaf node, returning `Some(cost)` if the cost
+///   can be determined, or `None` otherwise.
+/// - `lower_bound_fn`: A function that provides a lower bound on the cost for a given node, used
+///   for pruning suboptimal branches.
+/// - `priority_fn`: A function that evaluates a node for selection, returning `Some(priority)` where higher
+///   priorities are better, or `None` if the node cannot be evaluated. Such nodes are selected last.
+/// - `beam_width`: The maximum number of nodes expanded together at each depth level. It must be
+///   positive.
+///
+/// # Returns
+/// An iterator that yields nodes reachable from the start node in beam stack search order.
+/// The iterator is lazy and will only compute successors as needed.
+#[allow(clippy::type_complexity)]
+pub fn bss_reach<C, N, IN, FN, FL, FC, FC2, FP, P>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+    priority_fn: FP,
+    beam_width: usize,
+) -> Reachable<BeamStackContainer<C, N, FN, FL, FC, FC2, FP, P>>
+where
+    C: Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+    FC2: Fn(&N) -> Option<C>,
+    FP: Fn(&N) -> Option<P>,
+    P: Ord + Copy,
+{
+    let container = BeamStackContainer::new(
+        start,
+        successor_fn,
+        leaf_check_fn,
+        cost_fn,
+        lower_bound_fn,
+        priority_fn,
+        beam_width,
+    );
+    Reachable::new(container)
+}
+
+/// Find the leaf node with the lowest cost by using Beam Stack Search
+///
+/// - `start` is the start node.
+/// - `successor_fn` returns a list of successors for a given node.
+/// - `leaf_check_fn` check if a node is leaf or not
+/// - `cost_fn` returns the final cost of a leaf node
+/// - `lower_bound_fn` returns the lower bound of a given node to decide whether to search deeper or not
+/// - `priority_fn` returns the priority of a given node to sort and select the beam
+/// - `beam_width` decides maximum number of nodes expanded together at each depth, and must be positive
+/// - `max_ops` is the maximum number of search operations to perform
+/// - `time_limit` is the maximum duration allowed for the search operation
+///
+/// This function returns Some of a tuple of (cost, leaf node, proven) if found, otherwise returns None.
+/// `proven` is `true` when the search space was exhausted, i.e. the returned leaf is optimal.
+#[allow(clippy::too_many_arguments)]
+pub fn bss<C, N, IN, FN, FL, FC, FC2, FP, P>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+    priority_fn: FP,
+    beam_width: usize,
+    max_ops: usize,
+    time_limit: Duration,
+) -> Option<(C, N, bool)>
+where
+    C: Ord + Copy,
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    FC: Copy + Fn(&N) -> Option<C>,
+    FL: Copy + Fn(&N) -> bool,
+    FC2: Fn(&N) -> Option<C>,
+    FP: Fn(&N) -> Option<P>,
+    P: Ord + Copy,
+{
+    let mut res = bss_reach(
+        start,
+        successor_fn,
+        leaf_check_fn,
+        cost_fn,
+        lower_bound_fn,
+        priority_fn,
+        beam_width,
+    );
+    let (cost, node) = find_best(
+        &mut res,
+        leaf_check_fn,
+        cost_fn,
+        max_ops,
+        time_limit,
+        |_, _| {},
+    )?;
+    Some((cost, node, res.container().is_exhausted()))
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::bss;
+    type Node = Vec<bool>;
+    #[test]
+    fn test_bss() {
+        let weights = [4, 2, 6, 3, 4];
+        let profits = [100, 20, 2, 5, 10];
+        let capacity = 8;
+        let total_items = weights.len();
+
+        let successor_fn = |n: &Node| {
+            if n.len() == total_items {
+                return vec![];
+            }
+
+            let total_weight: u32 = n
+                .iter()
+                .copied()
+                .enumerate()
+                .map(|(i, b)| if b { weights[i] } else { 0 })
+                .sum();
+
+            let mut children = vec![];
+
+            let next_idx = n.len();
+            if capacity >= total_weight + weights[next_idx] {
+                let mut c1 = n.clone();
+                c1.push(true);
+                children.push(c1);
+            }
+
+            let mut c2 = n.clone();
+            c2.push(false);
+            children.push(c2);
+
+            children
+        };
+
+        let total_profit = |n: &Node| {
+            let s: u32 = n
+                .iter()
+                .copied()
+                .enumerate()
+                .map(|(i, b)| if b { profits[i] } else { 0 })
+                .sum();
+            s
+        };
+
+        let lower_bound_fn = |n: &Node| {
+            let current_profit = total_profit(n);
+            let max_remained_profit: u32 = profits[n.len()..].iter().sum();
+            Some(u32::MAX - (current_profit + max_remained_profit))
+        };
+
+        let cost_fn = |n: &Node| Some(u32::MAX - total_profit(n));
+        // prefer not taking items, so that the first beam misses the optimum
+        let priority_fn = |n: &Node| Some(n.iter().filter(|&&b| !b).count());
+        let leaf_check_fn = |n: &Node| n.len() == total_items;
+
+        let run_with = |priority_fn: fn(&Node) -> Option<usize>, beam_width, max_ops| {
+            bss(
+                vec![],
+                successor_fn,
+                leaf_check_fn,
+                cost_fn,
+                lower_bound_fn,
+                priority_fn,
+                beam_width,
+                max_ops,
+                Duration::from_secs(10),
+            )
+            .map(|(cost, node, proven)| (u32::MAX - cost, node, proven))
+        };
+        let run = |max_ops| run_with(priority_fn, 1, max_ops);
+
+        // the first beam reaches the empty knapsack
+        assert_eq!(run(6), Some((0, vec![false; 5], false)));
+        assert_eq!(
+            run(usize::MAX),
+            Some((120, vec![true, true, false, false, false], true))
+        );
+
+        // nodes without a priority are still searched
+        assert_eq!(
+            run_with(|_| None, 1, usize::MAX),
+            Some((120, vec![true, true, false, false, false], true))
+        );
+        assert!(std::panic::catch_unwind(|| run_with(priority_fn, 0, usize::MAX)).is_err());
+    }
+}
@@ -4,6 +4,85 @@ use std::{collections::BinaryHeap, iter::FusedIterator, time::Duration};
 
 use crate::utils::ScoredItem;
 
+use super::{
+    observer::{SearchObserver, VisitCallback},
+    stop::{Any, MaxOps, SearchProgress, StopCondition, TimeLimit},
+};
+
+/// The reason why a traversal stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TerminationReason {
+    /// The traversal visited every node, so the best leaf found is the best leaf of the tree.
+    Exhausted,
+    /// The maximum number of operations was reached.
+    MaxOps,
+    /// The time limit was reached.
+    TimeLimit,
+    /// A leaf with the target cost was found.
+    TargetCost,
+    /// The best leaf did not improve for too long.
+    Stagnation,
+    /// The maximum number of leaves with a cost was found.
+    MaxSolutions,
+    /// The number of nodes waiting to be visited exceeded its limit.
+    MaxFrontier,
+    /// The traversal was cancelled from outside.
+    Cancelled,
+}
+
+/// An improvement of the best leaf found during a traversal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Improvement<C> {
+    /// The cost of the new best leaf.
+    pub cost: C,
+    /// The index of the operation that visited the new best leaf.
+    pub op_index: usize,
+    /// The time elapsed since the start of the traversal when the new best leaf was visited.
+    pub elapsed: Duration,
+}
+
+/// The outcome of a traversal, with the best leaves found and statistics about the search.
+#[derive(Debug, Clone)]
+pub struct SearchOutcome<C, N> {
+    /// The best leaves found and their costs, sorted by increasing cost.
+    pub solutions: Vec<(C, N)>,
+    /// The reason why the traversal stopped.
+    pub termination: TerminationReason,
+    /// The number of nodes visited.
+    pub n_visited: usize,
+    /// The number of leaves visited, feasible or not.
+    pub n_leaves: usize,
+    /// The number of leaves visited whose cost is `None`.
+    pub n_infeasible: usize,
+    /// The total duration of the traversal.
+    pub elapsed: Duration,
+    /// The successive improvements of the best leaf, in the order they were found.
+    pub improvements: Vec<Improvement<C>>,
+}
+
+impl<C, N> SearchOutcome<C, N> {
+    /// Returns the best leaf found and its cost, if any.
+    pub fn best(&self) -> Option<&(C, N)> {
+        self.solutions.first()
+    }
+
+    /// Consumes the outcome and returns the best leaf found and its cost, if any.
+    pub fn into_best(self) -> Option<(C, N)> {
+        self.solutions.into_iter().next()
+    }
+
+    /// Returns the time elapsed until the best leaf was found, if any.
+    pub fn time_to_best(&self) -> Option<Duration> {
+        self.improvements.last().map(|imp| imp.elapsed)
+    }
+
+    /// Returns `true` if the traversal visited every node.
+    pub fn is_exhausted(&self) -> bool {
+        self.termination == TerminationReason::Exhausted
+    }
+}
+
 /// Performs a generic traversal over a tree iterator, collecting the best leaf nodes based on their costs.
 ///
 /// This function iterates through the provided tree, checks each node to determine if it's a leaf using the `leaf_check_fn`,
@@ -23,7 +102,7 @@ use crate::utils::ScoredItem;
 /// - `callback_fn`: A mutable callback invoked as `callback_fn(n_step, &node)` for each visited node.
 ///
 /// # Returns
-/// A vector of tuples containing the cost and the node, limited to `queue_size`.
+/// A vector of tuples containing the cost and the node, limited to `queue_size` and sorted by increasing cost.
 pub fn traverse<C, N, FC, FL, CB>(
     tree: &mut impl FusedIterator<Item = N>,
     leaf_check_fn: FL,
@@ -31,45 +110,211 @@ pub fn traverse<C, N, FC, FL, CB>(
     max_ops: usize,
     time_limit: Duration,
     queue_size: usize,
-    mut callback_fn: CB,
+    callback_fn: CB,
 ) -> Vec<(C, N)>
 where
     C: Ord + Copy,
     FC: Fn(&N) -> Option<C>,
     FL: Fn(&N) -> bool,
     CB: FnMut(usize, &N),
+{
+    traverse_with_outcome(
+        tree,
+        leaf_check_fn,
+        cost_fn,
+        max_ops,
+        time_limit,
+        queue_size,
+        callback_fn,
+    )
+    .solutions
+}
+
+/// Performs a generic traversal over a tree iterator like [`traverse`], and reports how the search went.
+///
+/// The parameters are the same as those of [`traverse`].
+///
+/// # Returns
+/// A [`SearchOutcome`] holding the best leaves found, the reason why the traversal stopped, the numbers of
+/// visited nodes, leaves and infeasible leaves, the elapsed time and the successive improvements of the best leaf.
+pub fn traverse_with_outcome<C, N, FC, FL, CB>(
+    tree: &mut impl FusedIterator<Item = N>,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    max_ops: usize,
+    time_limit: Duration,
+    queue_size: usize,
+    callback_fn: CB,
+) -> SearchOutcome<C, N>
+where
+    C: Ord + Copy,
+    FC: Fn(&N) -> Option<C>,
+    FL: Fn(&N) -> bool,
+    CB: FnMut(usize, &N),
+{
+    traverse_until(
+        &mut SizeHintFrontier(tree),
+        leaf_check_fn,
+        cost_fn,
+        queue_size,
+        Any(MaxOps(max_ops), TimeLimit(time_limit)),
+        callback_fn,
+    )
+}
+
+/// Performs a generic traversal over a tree iterator until the given stop condition is met.
+///
+/// This function works like [`traverse_with_outcome`], but the traversal stops when `stop_condition` is met
+/// (or the tree is exhausted) instead of after a fixed number of operations or duration. The condition is checked
+/// before each node is pulled from the tree, see [`StopCondition`].
+///
+/// # Parameters
+/// - `tree`: A mutable reference to a fused iterator over the tree nodes, reporting its frontier size.
+/// - `leaf_check_fn`: A function that checks if a node is a leaf.
+/// - `cost_fn`: A function that computes the cost of a node, returning `None` if the cost cannot be determined.
+/// - `queue_size`: The maximum number of best nodes to keep in the result.
+/// - `stop_condition`: The condition to stop the traversal, e.g. `Any(MaxOps(1000), TargetCost(0))`.
+/// - `callback_fn`: A mutable callback invoked as `callback_fn(n_step, &node)` for each visited node.
+///
+/// # Returns
+/// A [`SearchOutcome`] holding the best leaves found along with the termination reason and statistics of the search.
+pub fn traverse_until<C, N, T, FC, FL, S, CB>(
+    tree: &mut T,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    queue_size: usize,
+    stop_condition: S,
+    callback_fn: CB,
+) -> SearchOutcome<C, N>
+where
+    C: Ord + Copy,
+    T: FusedIterator<Item = N> + Frontier,
+    FC: Fn(&N) -> Option<C>,
+    FL: Fn(&N) -> bool,
+    S: StopCondition<C>,
+    CB: FnMut(usize, &N),
+{
+    traverse_observed(
+        tree,
+        leaf_check_fn,
+        cost_fn,
+        queue_size,
+        stop_condition,
+        VisitCallback(callback_fn),
+    )
+}
+
+/// Performs a generic traversal over a tree iterator until the given stop condition is met, notifying an observer.
+///
+/// This function works like [`traverse_until`], but instead of a callback for the visited nodes, it notifies
+/// `observer` of each visited node, each evaluated leaf, each improvement of the best leaf and the outcome, see
+/// [`SearchObserver`]. Expansions and pruning are reported by the containers that support an observer.
+///
+/// # Parameters
+/// - `tree`: A mutable reference to a fused iterator over the tree nodes, reporting its frontier size.
+/// - `leaf_check_fn`: A function that checks if a node is a leaf.
+/// - `cost_fn`: A function that computes the cost of a node, returning `None` if the cost cannot be determined.
+/// - `queue_size`: The maximum number of best nodes to keep in the result.
+/// - `stop_condition`: The condition to stop the traversal, e.g. `Any(MaxOps(1000), TargetCost(0))`.
+/// - `observer`: The observer notified of the events of the traversal.
+///
+/// # Returns
+/// A [`SearchOutcome`] holding the best leaves found along with the termination reason and statistics of the search.
+pub fn traverse_observed<C, N, T, FC, FL, S, O>(
+    tree: &mut T,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    queue_size: usize,
+    mut stop_condition: S,
+    mut observer: O,
+) -> SearchOutcome<C, N>
+where
+    C: Ord + Copy,
+    T: FusedIterator<Item = N> + Frontier,
+    FC: Fn(&N) -> Option<C>,
+    FL: Fn(&N) -> bool,
+    S: StopCondition<C>,
+    O: SearchObserver<N, C>,
 {
     let mut queue = BinaryHeap::new();
+    let mut n_visited = 0;
+    let mut n_leaves = 0;
+    let mut n_infeasible = 0;
+    let mut improvements: Vec<Improvement<C>> = vec![];
 
     let start = std::time::Instant::now();
-    for (i, n) in tree.enumerate() {
-        if i >= max_ops || start.elapsed() >= time_limit {
-            break;
+    let termination = loop {
+        // check the stop condition before pulling the next node so that no node is expanded without being evaluated
+        let progress = SearchProgress {
+            n_ops: n_visited,
+            n_solutions: n_leaves - n_infeasible,
+            elapsed: start.elapsed(),
+            best_cost: improvements.last().map(|imp| imp.cost),
+            last_improvement: improvements.last().copied(),
+            frontier_size: tree.frontier_size(),
+        };
+        if let Some(reason) = stop_condition.should_stop(&progress) {
+            // a tree known to be empty has been exhausted, even if the stop condition is met too
+            if progress.frontier_size == Some(0) {
+                break TerminationReason::Exhausted;
+            }
+            break reason;
         }
-        callback_fn(i, &n);
+        let Some(n) = tree.next() else {
+            break TerminationReason::Exhausted;
+        };
+        let i = n_visited;
+        n_visited += 1;
+        observer.on_visit(i, &n);
 
         if !leaf_check_fn(&n) {
             continue;
         }
+        n_leaves += 1;
 
-        let Some(cost) = cost_fn(&n) else {
+        let cost = cost_fn(&n);
+        observer.on_leaf(&n, cost);
+        let Some(cost) = cost else {
+            n_infeasible += 1;
             continue;
         };
 
+        if improvements.last().is_none_or(|imp| imp.cost > cost) {
+            improvements.push(Improvement {
+                cost,
+                op_index: i,
+                elapsed: start.elapsed(),
+            });
+            observer.on_incumbent(&n, cost);
+        }
+
         queue.push(ScoredItem::from((cost, n)));
         if queue.len() > queue_size {
             queue.pop();
         }
-    }
+    };
 
-    queue
+    let solutions = queue
+        .into_sorted_vec()
         .into_iter()
         .take(queue_size)
         .map(|item| {
             let (cost, n) = item.into_inner();
             (cost, n)
         })
-        .collect()
+        .collect();
+
+    let outcome = SearchOutcome {
+        solutions,
+        termination,
+        n_visited,
+        n_leaves,
+        n_infeasible,
+        elapsed: start.elapsed(),
+        improvements,
+    };
+    observer.on_finish(&outcome);
+    outcome
 }
 
 /// Finds the best (lowest cost) leaf node in the tree iterator within the given constraints.
@@ -114,6 +359,149 @@ where
     .pop()
 }
 
+/// Finds the best (lowest cost) leaf node in the tree iterator like [`find_best`], and reports how the search went.
+///
+/// The parameters are the same as those of [`find_best`].
+///
+/// # Returns
+/// A [`SearchOutcome`] holding at most one solution, the best leaf found, along with the statistics of the search.
+pub fn find_best_with_outcome<C, N, FC, FL, CB>(
+    tree: &mut impl FusedIterator<Item = N>,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    max_ops: usize,
+    time_limit: Duration,
+    callback_fn: CB,
+) -> SearchOutcome<C, N>
+where
+    C: Ord + Copy,
+    FC: Fn(&N) -> Option<C>,
+    FL: Fn(&N) -> bool,
+    CB: FnMut(usize, &N),
+{
+    traverse_with_outcome(
+        tree,
+        leaf_check_fn,
+        cost_fn,
+        max_ops,
+        time_limit,
+        1, // only need the best one
+        callback_fn,
+    )
+}
+
+/// Finds the best (lowest cost) leaf node in the tree iterator until the given stop condition is met.
+///
+/// This function is a convenience wrapper around [`traverse_until`] that keeps only the single best node.
+///
+/// # Parameters
+/// - `tree`: A mutable reference to a fused iterator over the tree nodes, reporting its frontier size.
+/// - `leaf_check_fn`: A function that checks if a node is a leaf.
+/// - `cost_fn`: A function that computes the cost of a node, returning `None` if the cost cannot be determined.
+/// - `stop_condition`: The condition to stop the traversal.
+/// - `callback_fn`: A mutable callback invoked as `callback_fn(n_step, &node)` for each visited node.
+///
+/// # Returns
+/// A [`SearchOutcome`] holding at most one solution, the best leaf found, along with the statistics of the search.
+pub fn find_best_until<C, N, T, FC, FL, S, CB>(
+    tree: &mut T,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    stop_condition: S,
+    callback_fn: CB,
+) -> SearchOutcome<C, N>
+where
+    C: Ord + Copy,
+    T: FusedIterator<Item = N> + Frontier,
+    FC: Fn(&N) -> Option<C>,
+    FL: Fn(&N) -> bool,
+    S: StopCondition<C>,
+    CB: FnMut(usize, &N),
+{
+    traverse_until(
+        tree,
+        leaf_check_fn,
+        cost_fn,
+        1, // only need the best one
+        stop_condition,
+        callback_fn,
+    )
+}
+
+/// Finds the best (lowest cost) leaf node in the tree iterator until the given stop condition is met, notifying an
+/// observer.
+///
+/// This function is a convenience wrapper around [`traverse_observed`] that keeps only the single best node.
+///
+/// # Parameters
+/// - `tree`: A mutable reference to a fused iterator over the tree nodes, reporting its frontier size.
+/// - `leaf_check_fn`: A function that checks if a node is a leaf.
+/// - `cost_fn`: A function that computes the cost of a node, returning `None` if the cost cannot be determined.
+/// - `stop_condition`: The condition to stop the traversal.
+/// - `observer`: The observer notified of the events of the traversal.
+///
+/// # Returns
+/// A [`SearchOutcome`] holding at most one solution, the best leaf found, along with the statistics of the search.
+pub fn find_best_observed<C, N, T, FC, FL, S, O>(
+    tree: &mut T,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    stop_condition: S,
+    observer: O,
+) -> SearchOutcome<C, N>
+where
+    C: Ord + Copy,
+    T: FusedIterator<Item = N> + Frontier,
+    FC: Fn(&N) -> Option<C>,
+    FL: Fn(&N) -> bool,
+    S: StopCondition<C>,
+    O: SearchObserver<N, C>,
+{
+    traverse_observed(
+        tree,
+        leaf_check_fn,
+        cost_fn,
+        1, // only need the best one
+        stop_condition,
+        observer,
+    )
+}
+
+/// A trait for traversals that can report the number of nodes waiting to be visited.
+///
+/// The size is used by stop conditions such as [`MaxFrontier`](super::MaxFrontier), and a size of zero
+/// tells that the traversal is exhausted even when a stop condition is met after its last node. The
+/// default implementation reports nothing, so any iterator can implement this trait with an empty `impl`.
+pub trait Frontier {
+    /// Returns the number of nodes waiting to be visited, if known.
+    fn frontier_size(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// An iterator whose frontier size is only known to be zero when its size hint tells that it is empty.
+struct SizeHintFrontier<'a, I>(&'a mut I);
+
+impl<I: Iterator> Iterator for SizeHintFrontier<'_, I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for SizeHintFrontier<'_, I> {}
+
+impl<I: Iterator> Frontier for SizeHintFrontier<'_, I> {
+    fn frontier_size(&self) -> Option<usize> {
+        (self.0.size_hint().1 == Some(0)).then_some(0)
+    }
+}
+
 /// A trait representing a container that holds the frontier of nodes during a traversal.
 ///
 /// This trait is the core abstraction used by traversal drivers such as [`Reachable`].
@@ -173,6 +561,13 @@ pub trait NodeContainer {
     /// The `node` itself must not be consumed or modified by this method; it is passed
     /// by shared reference so that it can also be yielded to the caller of the traversal.
     fn expand_and_push(&mut self, node: &Self::Node);
+
+    /// Returns the number of nodes waiting to be visited, if the container keeps track of it.
+    ///
+    /// The default implementation returns `None`.
+    fn frontier_size(&self) -> Option<usize> {
+        None
+    }
 }
 
 /// An iterator that traverses a tree or graph by yielding nodes reachable from a starting point.
@@ -206,6 +601,22 @@ impl<C> Reachable<C> {
     pub fn new(to_see: C) -> Self {
         Self { to_see }
     }
+
+    /// Returns a reference to the underlying node container.
+    ///
+    /// This is useful to inspect the state of the traversal (e.g. whether the search
+    /// has proven optimality) after it has been driven by [`traverse`] or [`find_best`].
+    pub fn container(&self) -> &C {
+        &self.to_see
+    }
+
+    /// Consumes the iterator and returns the underlying node container.
+    ///
+    /// This is useful to wrap the container of an existing traversal into another container,
+    /// e.g. [`dedup_reach`](super::dedup_reach).
+    pub fn into_container(self) -> C {
+        self.to_see
+    }
 }
 
 impl<N, C> Iterator for Reachable<C>
@@ -219,6 +630,126 @@ where
         self.to_see.expand_and_push(&node);
         Some(node)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.to_see.frontier_size() {
+            Some(0) => (0, Some(0)),
+            _ => (0, None),
+        }
+    }
 }
 
 impl<C> FusedIterator for Reachable<C> where C: NodeContainer {}
+
+impl<C> Frontier for Reachable<C>
+where
+    C: NodeContainer,
+{
+    fn frontier_size(&self) -> Option<usize> {
+        self.to_see.frontier_size()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{TerminationReason, find_best_with_outcome};
+    use crate::traversal::functional::dfs_reach;
+
+    type Node = Vec<bool>;
+
+    #[test]
+    fn test_outcome() {
+        let weights = [4, 2, 6, 3];
+        let profits = [100, 20, 2, 5];
+        let capacity = 8;
+        let total_items = weights.len();
+
+        // every subset is generated, overweight ones are infeasible
+        let successor_fn = |n: &Node| {
+            if n.len() == total_items {
+                return vec![];
+            }
+            let mut c1 = n.clone();
+            c1.push(true);
+            let mut c2 = n.clone();
+            c2.push(false);
+            vec![c1, c2]
+        };
+        let total = |n: &Node, values: &[u32]| -> u32 {
+            n.iter()
+                .copied()
+                .enumerate()
+                .map(|(i, b)| if b { values[i] } else { 0 })
+                .sum()
+        };
+        let cost_fn = |n: &Node| {
+            if total(n, &weights) > capacity {
+                None
+            } else {
+                Some(1000 - total(n, &profits))
+            }
+        };
+        let leaf_check_fn = |n: &Node| n.len() == total_items;
+        let time_limit = Duration::from_secs(10);
+
+        let outcome = find_best_with_outcome(
+            &mut dfs_reach(vec![], successor_fn),
+            leaf_check_fn,
+            cost_fn,
+            usize::MAX,
+            time_limit,
+            |_, _| {},
+        );
+        assert_eq!(outcome.termination, TerminationReason::Exhausted);
+        assert_eq!(outcome.n_visited, 31);
+        assert_eq!(outcome.n_leaves, 16);
+        // only 9 subsets weigh at most 8
+        assert_eq!(outcome.n_infeasible, 7);
+        assert_eq!(outcome.best(), Some(&(880, vec![true, true, false, false])));
+        assert!(
+            outcome
+                .improvements
+                .windows(2)
+                .all(|w| w[0].cost > w[1].cost)
+        );
+        assert_eq!(outcome.improvements.last().map(|imp| imp.cost), Some(880));
+        assert!(outcome.time_to_best() <= Some(outcome.elapsed));
+
+        let outcome = find_best_with_outcome(
+            &mut dfs_reach(vec![], successor_fn),
+            leaf_check_fn,
+            cost_fn,
+            10,
+            time_limit,
+            |_, _| {},
+        );
+        assert_eq!(outcome.termination, TerminationReason::MaxOps);
+        assert_eq!(outcome.n_visited, 10);
+
+        // the operation limit is reached exactly when the whole tree has been visited
+        let outcome = find_best_with_outcome(
+            &mut dfs_reach(vec![], successor_fn),
+            leaf_check_fn,
+            cost_fn,
+            31,
+            time_limit,
+            |_, _| {},
+        );
+        assert_eq!(outcome.termination, TerminationReason::Exhausted);
+        assert_eq!(outcome.n_visited, 31);
+
+        let outcome = find_best_with_outcome(
+            &mut dfs_reach(vec![], successor_fn),
+            leaf_check_fn,
+            cost_fn,
+            usize::MAX,
+            Duration::ZERO,
+            |_, _| {},
+        );
+        assert_eq!(outcome.termination, TerminationReason::TimeLimit);
+        assert_eq!(outcome.n_visited, 0);
+        assert!(outcome.into_best().is_none());
+    }
+}
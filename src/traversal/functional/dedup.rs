@@ -0,0 +1,214 @@
+//! Duplicate Detection
+
+use std::{collections::HashMap, hash::Hash};
+
+use super::common::{NodeContainer, Reachable};
+
+/// A container that adds duplicate detection to another [`NodeContainer`].
+///
+/// The container keeps a closed set (a transposition table) mapping the state key of every
+/// visited node to the path cost it was reached with. A node popped from the inner container is
+/// skipped, without being expanded, when its state has already been visited with a path cost
+/// that is not greater than its own. When the path cost is not known (unit cost), this means
+/// that every state is visited only once.
+///
+/// The wrapped container must not rely on its popped nodes being expanded, which holds for
+/// frontier-based containers such as breadth-first, depth-first, priority-first, A* or
+/// branch-and-bound, but not for [`MonteCarloContainer`](super::MonteCarloContainer).
+pub struct ClosedSetContainer<NC, K, G, FK, FG> {
+    inner: NC,
+    seen: HashMap<K, G>,
+    key_fn: FK,
+    path_cost_fn: FG,
+    n_duplicates: usize,
+}
+
+impl<NC, K, G, FK, FG> ClosedSetContainer<NC, K, G, FK, FG>
+where
+    NC: NodeContainer,
+    K: Eq + Hash,
+    G: Ord,
+    FK: Fn(&NC::Node) -> K,
+    FG: Fn(&NC::Node) -> G,
+{
+    /// Creates a new `ClosedSetContainer` wrapping the given container.
+    pub fn new(inner: NC, key_fn: FK, path_cost_fn: FG) -> Self {
+        Self {
+            inner,
+            seen: HashMap::new(),
+            key_fn,
+            path_cost_fn,
+            n_duplicates: 0,
+        }
+    }
+
+    /// Returns a reference to the wrapped container.
+    pub fn inner(&self) -> &NC {
+        &self.inner
+    }
+
+    /// Returns the number of distinct states visited so far.
+    pub fn num_states(&self) -> usize {
+        self.seen.len()
+    }
+
+    /// Returns the number of popped nodes skipped as duplicates so far.
+    pub fn num_duplicates(&self) -> usize {
+        self.n_duplicates
+    }
+}
+
+impl<NC, K, G, FK, FG> NodeContainer for ClosedSetContainer<NC, K, G, FK, FG>
+where
+    NC: NodeContainer,
+    K: Eq + Hash,
+    G: Ord,
+    FK: Fn(&NC::Node) -> K,
+    FG: Fn(&NC::Node) -> G,
+{
+    type Node = NC::Node;
+
+    fn pop(&mut self) -> Option<Self::Node> {
+        while let Some(node) = self.inner.pop() {
+            let key = (self.key_fn)(&node);
+            let g = (self.path_cost_fn)(&node);
+            if self.seen.get(&key).is_some_and(|best| *best <= g) {
+                self.n_duplicates += 1;
+                continue;
+            }
+            self.seen.insert(key, g);
+            return Some(node);
+        }
+        None
+    }
+
+    fn expand_and_push(&mut self, node: &Self::Node) {
+        self.inner.expand_and_push(node);
+    }
+
+    fn frontier_size(&self) -> Option<usize> {
+        self.inner.frontier_size()
+    }
+}
+
+/// Adds duplicate detection to a traversal iterator.
+///
+/// This function wraps the container of the given traversal so that each state, identified by
+/// `key_fn`, is visited and expanded at most once. Use it when different paths lead to the same
+/// state (e.g. permutation problems) and the path used to reach a state does not matter.
+///
+/// # Parameters
+/// - `tree`: The traversal iterator to wrap, e.g. the result of [`bfs_reach`](super::bfs_reach).
+/// - `key_fn`: A function that returns the key of the state of a node, e.g. `|n| n.state_key()`
+///   for nodes implementing [`StateKey`](crate::node::StateKey).
+///
+/// # Returns
+/// An iterator that yields the nodes of `tree` whose state has not been visited yet.
+/// The iterator is lazy and will only compute successors as needed.
+#[allow(clippy::type_complexity)]
+pub fn dedup_reach<NC, K, FK>(
+    tree: Reachable<NC>,
+    key_fn: FK,
+) -> Reachable<ClosedSetContainer<NC, K, (), FK, fn(&NC::Node)>>
+where
+    NC: NodeContainer,
+    K: Eq + Hash,
+    FK: Fn(&NC::Node) -> K,
+{
+    let path_cost_fn: fn(&NC::Node) = |_| {};
+    let container = ClosedSetContainer::new(tree.into_container(), key_fn, path_cost_fn);
+    Reachable::new(container)
+}
+
+/// Adds duplicate detection that keeps the cheaper path to a traversal iterator.
+///
+/// This function wraps the container of the given traversal so that a state, identified by
+/// `key_fn`, is visited again only when it is reached with a strictly lower path cost than
+/// before. With a container that pops nodes in order of increasing path cost (e.g.
+/// [`astar_reach`](super::astar_reach) with a consistent heuristic) every state is visited once,
+/// by its cheapest path.
+///
+/// # Parameters
+/// - `tree`: The traversal iterator to wrap, e.g. the result of [`dfs_reach`](super::dfs_reach).
+/// - `key_fn`: A function that returns the key of the state of a node.
+/// - `path_cost_fn`: A function that returns the cost of the path from the root to a node.
+///
+/// # Returns
+/// An iterator that yields the nodes of `tree` whose state has not been visited yet with a
+/// lower or equal path cost. The iterator is lazy and will only compute successors as needed.
+pub fn dedup_by_cost_reach<NC, K, G, FK, FG>(
+    tree: Reachable<NC>,
+    key_fn: FK,
+    path_cost_fn: FG,
+) -> Reachable<ClosedSetContainer<NC, K, G, FK, FG>>
+where
+    NC: NodeContainer,
+    K: Eq + Hash,
+    G: Ord,
+    FK: Fn(&NC::Node) -> K,
+    FG: Fn(&NC::Node) -> G,
+{
+    let container = ClosedSetContainer::new(tree.into_container(), key_fn, path_cost_fn);
+    Reachable::new(container)
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{super::dfs_reach, dedup_by_cost_reach, dedup_reach};
+    use crate::traversal::functional::find_best;
+
+    /// the items scheduled so far, in order
+    type Node = Vec<usize>;
+
+    #[test]
+    fn test_dedup() {
+        let weights = [3, 1, 4, 2];
+        let total_items = weights.len();
+
+        let successor_fn = |n: &Node| {
+            (0..total_items)
+                .filter(|i| !n.contains(i))
+                .map(|i| {
+                    let mut c = n.clone();
+                    c.push(i);
+                    c
+                })
+                .collect::<Vec<_>>()
+        };
+        // the set of scheduled items
+        let key_fn = |n: &Node| n.iter().fold(0u32, |acc, &i| acc | (1 << i));
+        // each item costs its weight times its completion position
+        let path_cost_fn = |n: &Node| {
+            n.iter()
+                .enumerate()
+                .map(|(pos, &i)| weights[i] * (pos + 1))
+                .sum::<usize>()
+        };
+        let leaf_check_fn = |n: &Node| n.len() == total_items;
+        let cost_fn = |n: &Node| Some(path_cost_fn(n));
+
+        // 1 + 4 + 12 + 24 + 24 nodes in the tree, but only 2^4 states
+        assert_eq!(dfs_reach(vec![], successor_fn).count(), 65);
+        let mut res = dedup_reach(dfs_reach(vec![], successor_fn), key_fn);
+        assert_eq!(res.by_ref().count(), 16);
+        assert_eq!(res.container().num_states(), 16);
+
+        // keeping the cheaper path finds the optimal schedule with fewer visits
+        let mut res = dedup_by_cost_reach(dfs_reach(vec![], successor_fn), key_fn, path_cost_fn);
+        let (cost, best_node) = find_best(
+            &mut res,
+            leaf_check_fn,
+            cost_fn,
+            usize::MAX,
+            Duration::from_secs(10),
+            |_, _| {},
+        )
+        .expect("dedup should find a valid solution");
+        assert_eq!(cost, 20);
+        assert_eq!(best_node, vec![2, 0, 3, 1]);
+        assert!(res.container().num_duplicates() > 0);
+        assert!(res.container().num_states() + res.container().num_duplicates() < 65);
+    }
+}
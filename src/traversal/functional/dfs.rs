@@ -3,8 +3,9 @@
 use std::time::Duration;
 
 use super::{
-    common::{NodeContainer, Reachable},
+    common::{NodeContainer, Reachable, SearchOutcome, find_best_until},
     find_best,
+    stop::StopCondition,
 };
 
 /// A container for Depth-First traversal.
@@ -43,6 +44,10 @@ where
             self.to_see.push(s);
         }
     }
+
+    fn frontier_size(&self) -> Option<usize> {
+        Some(self.to_see.len())
+    }
 }
 
 /// Creates a Depth-First Search traversal iterator starting from the given node.
@@ -102,6 +107,34 @@ where
     )
 }
 
+/// Find the leaf node with the lowest cost until the given stop condition is met by using Depth First Search
+///
+/// - `start` is the start node.
+/// - `successor_fn` returns a list of successors for a given node.
+/// - `leaf_check_fn` check if a node is leaf or not
+/// - `cost_fn` returns the final cost of a leaf node
+/// - `stop_condition` decides when to stop the search, e.g. `Any(MaxOps(1000), TargetCost(0))`
+///
+/// This function returns a [`SearchOutcome`] holding the best leaf found, if any, and the reason why the search stopped
+pub fn dfs_until<N, IN, FN, FC, C, FL, S>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    stop_condition: S,
+) -> SearchOutcome<C, N>
+where
+    C: Ord + Copy + Default,
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+    S: StopCondition<C>,
+{
+    let mut res = dfs_reach(start, successor_fn);
+    find_best_until(&mut res, leaf_check_fn, cost_fn, stop_condition, |_, _| {})
+}
+
 #[cfg(test)]
 mod test {
     use std::time::Duration;
@@ -0,0 +1,172 @@
+//! Dominance Pruning
+
+use std::{collections::HashMap, hash::Hash};
+
+/// A trait representing a filter that discards nodes dominated by previously seen nodes.
+///
+/// Containers such as [`BranchAndBoundContainer`](super::BranchAndBoundContainer) consult the
+/// filter before pushing a successor and drop the successor if it is dominated.
+/// The unit type `()` is a filter that never discards any node.
+pub trait DominanceFilter<N> {
+    /// Returns `true` if the node is dominated by a node seen before and should be discarded.
+    /// Otherwise, the node is recorded so that later nodes can be compared with it.
+    fn is_dominated(&mut self, node: &N) -> bool;
+}
+
+impl<N> DominanceFilter<N> for () {
+    fn is_dominated(&mut self, _node: &N) -> bool {
+        false
+    }
+}
+
+/// A store of non-dominated labels grouped by the key of the remaining subproblem.
+///
+/// For each key, the store keeps the labels of the nodes that were not dominated when they were
+/// seen. A new node is dominated if one of the labels stored for its key dominates its label.
+/// Otherwise its label is stored, and the stored labels it dominates are removed.
+pub struct DominanceStore<K, L, FK, FS, FD> {
+    labels: HashMap<K, Vec<L>>,
+    key_fn: FK,
+    label_fn: FS,
+    dominates_fn: FD,
+    n_dominated: usize,
+}
+
+impl<K, L, FK, FS, FD> DominanceStore<K, L, FK, FS, FD> {
+    /// Creates a new `DominanceStore` with the given parameters.
+    ///
+    /// - `key_fn` returns the key of the remaining subproblem of a node.
+    /// - `label_fn` returns the label of a node.
+    /// - `dominates_fn(a, b)` returns `true` if a node with label `a` is at least as good as a
+    ///   node with label `b` and the same key.
+    pub fn new(key_fn: FK, label_fn: FS, dominates_fn: FD) -> Self {
+        Self {
+            labels: HashMap::new(),
+            key_fn,
+            label_fn,
+            dominates_fn,
+            n_dominated: 0,
+        }
+    }
+
+    /// Returns the number of nodes discarded as dominated so far.
+    pub fn num_dominated(&self) -> usize {
+        self.n_dominated
+    }
+}
+
+impl<N, K, L, FK, FS, FD> DominanceFilter<N> for DominanceStore<K, L, FK, FS, FD>
+where
+    K: Eq + Hash,
+    FK: Fn(&N) -> K,
+    FS: Fn(&N) -> L,
+    FD: Fn(&L, &L) -> bool,
+{
+    fn is_dominated(&mut self, node: &N) -> bool {
+        let label = (self.label_fn)(node);
+        let labels = self.labels.entry((self.key_fn)(node)).or_default();
+        if labels.iter().any(|l| (self.dominates_fn)(l, &label)) {
+            self.n_dominated += 1;
+            return true;
+        }
+        labels.retain(|l| !(self.dominates_fn)(&label, l));
+        labels.push(label);
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{super::bbs_reach, DominanceStore};
+    use crate::traversal::functional::{bbs_dominance_reach, find_best};
+
+    type Node = Vec<bool>;
+
+    #[test]
+    fn test_dominance() {
+        let weights = [2, 3, 5, 1, 4, 2];
+        let profits = [10, 12, 20, 3, 15, 7];
+        let capacity = 9;
+        let total_items = weights.len();
+
+        let total = |n: &Node, values: &[u32]| -> u32 {
+            n.iter()
+                .copied()
+                .enumerate()
+                .map(|(i, b)| if b { values[i] } else { 0 })
+                .sum()
+        };
+
+        let successor_fn = |n: &Node| {
+            if n.len() == total_items {
+                return vec![];
+            }
+
+            let mut children = vec![];
+
+            let next_idx = n.len();
+            if capacity >= total(n, &weights) + weights[next_idx] {
+                let mut c1 = n.clone();
+                c1.push(true);
+                children.push(c1);
+            }
+
+            let mut c2 = n.clone();
+            c2.push(false);
+            children.push(c2);
+
+            children
+        };
+
+        let lower_bound_fn = |n: &Node| {
+            let max_remained_profit: u32 = profits[n.len()..].iter().sum();
+            Some(u32::MAX - (total(n, &profits) + max_remained_profit))
+        };
+        let cost_fn = |n: &Node| Some(u32::MAX - total(n, &profits));
+        let leaf_check_fn = |n: &Node| n.len() == total_items;
+
+        // with the same items left, a lighter and more profitable knapsack is at least as good
+        let dominance = DominanceStore::new(
+            |n: &Node| n.len(),
+            |n: &Node| (total(n, &weights), total(n, &profits)),
+            |a: &(u32, u32), b: &(u32, u32)| a.0 <= b.0 && a.1 >= b.1,
+        );
+
+        let mut n_plain = 0;
+        let (plain_cost, _) = find_best(
+            &mut bbs_reach(vec![], successor_fn, leaf_check_fn, cost_fn, lower_bound_fn),
+            leaf_check_fn,
+            cost_fn,
+            usize::MAX,
+            Duration::from_secs(10),
+            |_, _| n_plain += 1,
+        )
+        .expect("BBS should find a valid solution");
+
+        let mut res = bbs_dominance_reach(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            dominance,
+        );
+        let mut n_dominance = 0;
+        let (cost, _) = find_best(
+            &mut res,
+            leaf_check_fn,
+            cost_fn,
+            usize::MAX,
+            Duration::from_secs(10),
+            |_, _| n_dominance += 1,
+        )
+        .expect("BBS with dominance should find a valid solution");
+
+        assert_eq!(cost, plain_cost);
+        assert_eq!(u32::MAX - cost, 37);
+        assert!(res.container().dominance().num_dominated() > 0);
+        assert!(n_dominance < n_plain);
+    }
+}
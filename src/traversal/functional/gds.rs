@@ -3,8 +3,9 @@
 use std::time::Duration;
 
 use super::{
-    common::{NodeContainer, Reachable},
+    common::{NodeContainer, Reachable, SearchOutcome, find_best_until},
     find_best,
+    stop::StopCondition,
 };
 
 /// A container for Greedy traversal.
@@ -52,6 +53,10 @@ where
 
         self.next_node = best_successor.map(|(_, n)| n);
     }
+
+    fn frontier_size(&self) -> Option<usize> {
+        Some(usize::from(self.next_node.is_some()))
+    }
 }
 
 /// Creates a Greedy Search traversal iterator starting from the given node.
@@ -113,6 +118,32 @@ where
     )
 }
 
+/// Find the leaf node with the lowest cost until the given stop condition is met by using Greedy Search
+///
+/// The search stops when `stop_condition` is met, and this function returns a [`SearchOutcome`] holding the best
+/// leaf found, if any, and the reason why the search stopped
+pub fn gds_until<N, IN, FN, FC, FP, C, P, FR, S>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FR,
+    cost_fn: FC,
+    priority_fn: FP,
+    stop_condition: S,
+) -> SearchOutcome<C, N>
+where
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    FC: Fn(&N) -> Option<C>,
+    FP: Fn(&N) -> Option<P>,
+    C: Ord + Copy,
+    P: Ord + Copy,
+    FR: Fn(&N) -> bool,
+    S: StopCondition<C>,
+{
+    let mut res = gds_reach(start, successor_fn, priority_fn);
+    find_best_until(&mut res, leaf_check_fn, cost_fn, stop_condition, |_, _| {})
+}
+
 #[cfg(test)]
 mod test {
 
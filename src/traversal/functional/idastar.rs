@@ -0,0 +1,322 @@
+//! Iterative Deepening A* Search
+
+use std::time::Duration;
+
+use super::{
+    common::{NodeContainer, Reachable},
+    find_best,
+};
+
+/// A container for Iterative Deepening A* traversal.
+///
+/// Each iteration is a depth-first traversal that only descends into nodes whose lower bound
+/// does not exceed the current threshold. The next threshold is the smallest lower bound that
+/// exceeded the current one. Nodes whose lower bound is not better than the best leaf found so
+/// far are pruned, and the traversal ends once no pruned node can improve on that leaf.
+/// Nodes visited by an earlier iteration are expanded again but not returned by `pop`, so each
+/// node is yielded once.
+pub struct IterativeDeepeningAStarContainer<C, N, FN, FL, FC, FC2> {
+    root: N,
+    to_see: Vec<(bool, N)>,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+    threshold: Option<C>,
+    previous_threshold: Option<C>,
+    next_threshold: Option<C>,
+    current_best_cost: Option<C>,
+    current_seen: bool,
+}
+
+impl<C, N, IN, FN, FL, FC, FC2> IterativeDeepeningAStarContainer<C, N, FN, FL, FC, FC2>
+where
+    C: Ord + Copy,
+    N: Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+    FC2: Fn(&N) -> Option<C>,
+{
+    /// Creates a new `IterativeDeepeningAStarContainer` with the given parameters.
+    pub fn new(
+        start: N,
+        successor_fn: FN,
+        leaf_check_fn: FL,
+        cost_fn: FC,
+        lower_bound_fn: FC2,
+    ) -> Self {
+        let threshold = lower_bound_fn(&start);
+        let to_see = if threshold.is_some() {
+            vec![(false, start.clone())]
+        } else {
+            vec![]
+        };
+        Self {
+            root: start,
+            to_see,
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            threshold,
+            previous_threshold: None,
+            next_threshold: None,
+            current_best_cost: None,
+            current_seen: false,
+        }
+    }
+
+    /// Returns the lower bound threshold of the current iteration.
+    pub fn threshold(&self) -> Option<C> {
+        self.threshold
+    }
+}
+
+impl<C, N, IN, FN, FL, FC, FC2> NodeContainer
+    for IterativeDeepeningAStarContainer<C, N, FN, FL, FC, FC2>
+where
+    C: Ord + Copy,
+    N: Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+    FC2: Fn(&N) -> Option<C>,
+{
+    type Node = N;
+
+    fn pop(&mut self) -> Option<Self::Node> {
+        loop {
+            if self.to_see.is_empty()
+                && let Some(next_threshold) = self.next_threshold.take()
+                && self.current_best_cost.is_none_or(|c| c > next_threshold)
+            {
+                self.previous_threshold = self.threshold;
+                self.threshold = Some(next_threshold);
+                self.to_see.push((true, self.root.clone()));
+            }
+            let (seen, node) = self.to_see.pop()?;
+            self.current_seen = seen;
+            if !seen {
+                return Some(node);
+            }
+            // already yielded by an earlier iteration, only expanded to reach the new threshold
+            self.expand_and_push(&node);
+        }
+    }
+
+    fn expand_and_push(&mut self, node: &Self::Node) {
+        if (self.leaf_check_fn)(node) {
+            if let Some(cost) = (self.cost_fn)(node)
+                && self.current_best_cost.is_none_or(|c| c > cost)
+            {
+                self.current_best_cost = Some(cost);
+            }
+            return;
+        }
+
+        let Some(threshold) = self.threshold else {
+            return;
+        };
+        let seen = self.current_seen;
+        for s in (self.successor_fn)(node) {
+            let Some(lb) = (self.lower_bound_fn)(&s) else {
+                continue;
+            };
+            if self.current_best_cost.is_some_and(|c| c <= lb) {
+                continue;
+            }
+            if lb > threshold {
+                self.next_threshold = Some(self.next_threshold.map_or(lb, |t| t.min(lb)));
+            } else {
+                let seen = seen && self.previous_threshold.is_some_and(|t| lb <= t);
+                self.to_see.push((seen, s));
+            }
+        }
+    }
+
+    fn frontier_size(&self) -> Option<usize> {
+        // the root is pushed again when the next iteration starts
+        let restart = self.to_see.is_empty()
+            && self
+                .next_threshold
+                .is_some_and(|t| self.current_best_cost.is_none_or(|c| c > t));
+        Some(self.to_see.len() + usize::from(restart))
+    }
+}
+
+/// Creates an Iterative Deepening A* Search traversal iterator starting from the given node.
+///
+/// This function initializes a lazy iterator that performs repeated depth-first traversals bounded
+/// by a lower bound threshold. The first threshold is the lower bound of the start node, and each
+/// following iteration uses the smallest lower bound that exceeded the previous threshold.
+/// Memory usage is that of a depth-first traversal while the best leaf is found in the order of
+/// increasing lower bound.
+///
+/// # Parameters
+/// - `start`: The root node from which to begin the traversal.
+/// - `successor_fn`: A function that, given a node, returns an iterator over its successor nodes.
+/// - `leaf_check_fn`: A function that determines whether a given node is a leaf (terminal) node.
+/// - `cost_fn`: A function that computes the cost of a leaf node, returning `Some(cost)` if the cost
+///   can be determined, or `None` otherwise.
+/// - `lower_bound_fn`: A function that provides a lower bound on the cost for a given node, used
+///   as the threshold value and for pruning suboptimal branches.
+///
+/// # Returns
+/// An iterator that yields nodes reachable from the start node in Iterative Deepening A* order.
+/// The iterator is lazy and will only compute successors as needed.
+pub fn idastar_reach<C, N, IN, FN, FL, FC, FC2>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+) -> Reachable<IterativeDeepeningAStarContainer<C, N, FN, FL, FC, FC2>>
+where
+    C: Ord + Copy,
+    N: Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+    FC2: Fn(&N) -> Option<C>,
+{
+    let container = IterativeDeepeningAStarContainer::new(
+        start,
+        successor_fn,
+        leaf_check_fn,
+        cost_fn,
+        lower_bound_fn,
+    );
+    Reachable::new(container)
+}
+
+/// Find the leaf node with the lowest cost by using Iterative Deepening A*
+///
+/// - `start` is the start node.
+/// - `successor_fn` returns a list of successors for a given node.
+/// - `leaf_check_fn` check if a node is leaf or not
+/// - `cost_fn` returns the final cost of a leaf node
+/// - `lower_bound_fn` returns the lower bound of a given node, used as the iteration threshold
+/// - `max_ops` is the maximum number of search operations to perform
+/// - `time_limit` is the maximum duration allowed for the search operation
+///
+/// This function returns Some of a tuple of (cost, leaf node) if found, otherwise returns None
+pub fn idastar<C, N, IN, FN, FL, FC, FC2>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+    max_ops: usize,
+    time_limit: Duration,
+) -> Option<(C, N)>
+where
+    C: Ord + Copy,
+    N: Clone,
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    FC: Copy + Fn(&N) -> Option<C>,
+    FL: Copy + Fn(&N) -> bool,
+    FC2: Fn(&N) -> Option<C>,
+{
+    let mut res = idastar_reach(start, successor_fn, leaf_check_fn, cost_fn, lower_bound_fn);
+    find_best(
+        &mut res,
+        leaf_check_fn,
+        cost_fn,
+        max_ops,
+        time_limit,
+        |_, _| {},
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{idastar, idastar_reach};
+    type Node = Vec<bool>;
+    #[test]
+    fn test_idastar() {
+        let weights = [4, 2, 6, 3, 4];
+        let profits = [100, 20, 2, 5, 10];
+        let capacity = 8;
+        let total_items = weights.len();
+
+        let successor_fn = |n: &Node| {
+            if n.len() == total_items {
+                return vec![];
+            }
+
+            let total_weight: u32 = n
+                .iter()
+                .copied()
+                .enumerate()
+                .map(|(i, b)| if b { weights[i] } else { 0 })
+                .sum();
+
+            let mut children = vec![];
+
+            let next_idx = n.len();
+            if capacity >= total_weight + weights[next_idx] {
+                let mut c1 = n.clone();
+                c1.push(true);
+                children.push(c1);
+            }
+
+            let mut c2 = n.clone();
+            c2.push(false);
+            children.push(c2);
+
+            children
+        };
+
+        let total_profit = |n: &Node| {
+            let s: u32 = n
+                .iter()
+                .copied()
+                .enumerate()
+                .map(|(i, b)| if b { profits[i] } else { 0 })
+                .sum();
+            s
+        };
+
+        let lower_bound_fn = |n: &Node| {
+            let current_profit = total_profit(n);
+            let max_remained_profit: u32 = profits[n.len()..].iter().sum();
+            Some(u32::MAX - (current_profit + max_remained_profit))
+        };
+
+        let cost_fn = |n: &Node| Some(u32::MAX - total_profit(n));
+
+        let leaf_check_fn = |n: &Node| n.len() == total_items;
+
+        let max_ops = usize::MAX;
+        let time_limit = Duration::from_secs(10);
+
+        let (cost, best_node) = idastar(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            max_ops,
+            time_limit,
+        )
+        .expect("IDA* should find a valid solution");
+        let cost = u32::MAX - cost;
+
+        assert_eq!(cost, 120);
+        assert_eq!(best_node, vec![true, true, false, false, false]);
+
+        let tree = idastar_reach(vec![], successor_fn, leaf_check_fn, cost_fn, lower_bound_fn);
+        let visited: Vec<_> = tree.collect();
+        let mut nodes = visited.clone();
+        nodes.sort();
+        nodes.dedup();
+        assert_eq!(nodes.len(), visited.len());
+    }
+}
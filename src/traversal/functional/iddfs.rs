@@ -0,0 +1,280 @@
+//! Iterative Deepening Depth First Search
+
+use std::time::Duration;
+
+use super::{
+    common::{NodeContainer, Reachable},
+    find_best,
+};
+
+/// A container for Iterative Deepening Depth-First traversal.
+///
+/// Each iteration is a depth-first traversal limited to `depth_limit`. When an iteration has
+/// cut off at least one node with successors, the traversal restarts from the root with the
+/// depth limit increased by one. Nodes shallower than the depth limit are expanded again in
+/// every iteration, but only the nodes at the new depth limit are returned by `pop`, so each
+/// node is yielded once.
+pub struct IterativeDeepeningContainer<N, FN> {
+    root: N,
+    to_see: Vec<(usize, N)>,
+    successor_fn: FN,
+    depth_limit: usize,
+    current_depth: usize,
+    cutoff: bool,
+}
+
+impl<N, FN, IN> IterativeDeepeningContainer<N, FN>
+where
+    N: Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    /// Creates a new `IterativeDeepeningContainer` with the given successor function.
+    pub fn new(start: N, successor_fn: FN) -> Self {
+        Self {
+            to_see: vec![(0, start.clone())],
+            root: start,
+            successor_fn,
+            depth_limit: 0,
+            current_depth: 0,
+            cutoff: false,
+        }
+    }
+
+    /// Returns the depth limit of the current iteration.
+    pub fn depth_limit(&self) -> usize {
+        self.depth_limit
+    }
+}
+
+impl<N, FN, IN> NodeContainer for IterativeDeepeningContainer<N, FN>
+where
+    N: Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    type Node = N;
+
+    fn pop(&mut self) -> Option<Self::Node> {
+        loop {
+            if self.to_see.is_empty() && self.cutoff {
+                self.cutoff = false;
+                self.depth_limit += 1;
+                self.to_see.push((0, self.root.clone()));
+            }
+            let (depth, node) = self.to_see.pop()?;
+            self.current_depth = depth;
+            if depth == self.depth_limit {
+                return Some(node);
+            }
+            // already yielded by an earlier iteration, only expanded to reach the new depth
+            self.expand_and_push(&node);
+        }
+    }
+
+    fn expand_and_push(&mut self, node: &Self::Node) {
+        let depth = self.current_depth;
+        let successors = (self.successor_fn)(node);
+        if depth < self.depth_limit {
+            for s in successors {
+                self.to_see.push((depth + 1, s));
+            }
+        } else if successors.into_iter().next().is_some() {
+            self.cutoff = true;
+        }
+    }
+
+    fn frontier_size(&self) -> Option<usize> {
+        // the root is pushed again when the next iteration starts
+        let restart = self.to_see.is_empty() && self.cutoff;
+        Some(self.to_see.len() + usize::from(restart))
+    }
+}
+
+/// Creates an Iterative Deepening Depth-First Search traversal iterator starting from the given node.
+///
+/// This function initializes a lazy iterator that performs repeated depth-first traversals with a
+/// depth limit growing by one per iteration, until an iteration no longer cuts off any node.
+/// Each iteration yields the nodes at its depth limit in depth-first order, so shallower nodes
+/// are completely explored before deeper ones, like a breadth-first traversal, and every node is
+/// yielded once.
+///
+/// # Parameters
+/// - `start`: The root node from which to begin the traversal.
+/// - `successor_fn`: A function that, given a node, returns an iterator over its successor nodes.
+///
+/// # Returns
+/// An iterator that yields nodes reachable from the start node in iterative deepening order.
+/// The iterator is lazy and will only compute successors as needed.
+pub fn iddfs_reach<N, IN, FN>(
+    start: N,
+    successor_fn: FN,
+) -> Reachable<IterativeDeepeningContainer<N, FN>>
+where
+    N: Clone,
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+{
+    let container = IterativeDeepeningContainer::new(start, successor_fn);
+    Reachable::new(container)
+}
+
+/// Find the leaf node with the lowest cost by using Iterative Deepening Depth First Search
+///
+/// - `start` is the start node.
+/// - `successor_fn` returns a list of successors for a given node.
+/// - `leaf_check_fn` check if a node is leaf or not
+/// - `cost_fn` returns the final cost of a leaf node
+/// - `max_ops` is the maximum number of search operations to perform
+/// - `time_limit` is the maximum duration allowed for the search operation
+///
+/// This function returns Some of a tuple of (cost, leaf node) if found, otherwise returns None
+pub fn iddfs<N, IN, FN, FC, C, FL>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    max_ops: usize,
+    time_limit: Duration,
+) -> Option<(C, N)>
+where
+    N: Clone,
+    C: Ord + Copy,
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+{
+    let mut res = iddfs_reach(start, successor_fn);
+    find_best(
+        &mut res,
+        leaf_check_fn,
+        cost_fn,
+        max_ops,
+        time_limit,
+        |_, _| {},
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{iddfs, iddfs_reach};
+    use crate::traversal::functional::traverse;
+    type Node = Vec<bool>;
+    #[test]
+    fn test_iddfs() {
+        let weights = [3, 4, 6, 5];
+        let profits = [2, 3, 2, 4];
+        let capacity = 8;
+        let total_items = weights.len();
+
+        let successor_fn = |n: &Node| {
+            if n.len() == total_items {
+                return vec![];
+            }
+
+            let total_weight: u32 = n
+                .iter()
+                .copied()
+                .enumerate()
+                .map(|(i, b)| if b { weights[i] } else { 0 })
+                .sum();
+
+            let mut children = vec![];
+
+            let mut c1 = n.clone();
+            c1.push(false);
+            children.push(c1);
+
+            let next_idx = n.len();
+            if capacity >= total_weight + weights[next_idx] {
+                let mut c2 = n.clone();
+                c2.push(true);
+                children.push(c2);
+            }
+
+            children
+        };
+
+        let cost_fn = |n: &Node| {
+            let cost: u32 = n
+                .iter()
+                .copied()
+                .enumerate()
+                .map(|(i, b)| if b { profits[i] } else { 0 })
+                .sum();
+            Some(u32::MAX - cost)
+        };
+
+        let leaf_check_fn = |n: &Node| n.len() == total_items;
+        let max_ops = usize::MAX;
+        let time_limit = Duration::from_secs(10);
+
+        let (cost, best_node) = iddfs(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            max_ops,
+            time_limit,
+        )
+        .expect("IDDFS should find a valid solution");
+        let cost = u32::MAX - cost;
+
+        assert_eq!(cost, 6);
+        assert_eq!(best_node, vec![true, false, false, true]);
+
+        let mut tree = iddfs_reach(vec![], successor_fn);
+        let leaves = traverse(
+            &mut tree,
+            leaf_check_fn,
+            cost_fn,
+            max_ops,
+            time_limit,
+            3,
+            |_, _| {},
+        );
+        assert_eq!(leaves.len(), 3);
+        assert_eq!(leaves[0].1, vec![true, false, false, true]);
+        assert_ne!(leaves[0].1, leaves[1].1);
+        assert_ne!(leaves[1].1, leaves[2].1);
+    }
+
+    #[test]
+    fn test_iddfs_yields_each_node_once() {
+        // picking 1 ends the path, so leaves are found at every depth
+        let successor_fn = |n: &Vec<u8>| {
+            if n.len() == 3 || n.last() == Some(&1) {
+                return vec![];
+            }
+            [0, 1]
+                .into_iter()
+                .map(|i| {
+                    let mut c = n.clone();
+                    c.push(i);
+                    c
+                })
+                .collect()
+        };
+        let leaf_check_fn = |n: &Vec<u8>| n.len() == 3 || n.last() == Some(&1);
+        let cost_fn = |n: &Vec<u8>| Some(n.len());
+
+        let mut tree = iddfs_reach(vec![], successor_fn);
+        let leaves = traverse(
+            &mut tree,
+            leaf_check_fn,
+            cost_fn,
+            usize::MAX,
+            Duration::from_secs(10),
+            10,
+            |_, _| {},
+        );
+        assert_eq!(leaves.len(), 4);
+        let mut nodes: Vec<_> = leaves.into_iter().map(|(_, n)| n).collect();
+        nodes.sort();
+        nodes.dedup();
+        assert_eq!(nodes.len(), 4);
+    }
+}
@@ -0,0 +1,268 @@
+//! Improving Leaves
+
+use std::{
+    iter::FusedIterator,
+    time::{Duration, Instant},
+};
+
+use super::common::{Improvement, TerminationReason};
+
+/// An iterator adapter yielding the leaves that improve on every leaf found before.
+///
+/// It is created by [`improving_leaves`]. Each item is the improvement, holding the cost, the
+/// operation index and the elapsed time, along with the new best leaf.
+pub struct ImprovingLeaves<I, FL, FC, C> {
+    tree: I,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    max_ops: usize,
+    time_limit: Duration,
+    start: Option<Instant>,
+    n_visited: usize,
+    best_cost: Option<C>,
+    termination: Option<TerminationReason>,
+}
+
+impl<I, FL, FC, C> ImprovingLeaves<I, FL, FC, C> {
+    /// Returns the number of nodes visited so far.
+    pub fn n_visited(&self) -> usize {
+        self.n_visited
+    }
+
+    /// Returns the time elapsed since the first call to `next`.
+    pub fn elapsed(&self) -> Duration {
+        self.start.map_or(Duration::ZERO, |s| s.elapsed())
+    }
+
+    /// Returns the reason why the traversal stopped, or `None` if it can still yield leaves.
+    pub fn termination(&self) -> Option<TerminationReason> {
+        self.termination
+    }
+
+    /// Consumes the adapter and returns the underlying traversal.
+    pub fn into_inner(self) -> I {
+        self.tree
+    }
+}
+
+impl<I, N, FL, FC, C> Iterator for ImprovingLeaves<I, FL, FC, C>
+where
+    I: FusedIterator<Item = N>,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+    C: Ord + Copy,
+{
+    type Item = (Improvement<C>, N);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.termination.is_some() {
+            return None;
+        }
+        let start = *self.start.get_or_insert_with(Instant::now);
+
+        loop {
+            // a tree known to be empty is exhausted even if a limit is also reached
+            let limit = if self.n_visited >= self.max_ops {
+                Some(TerminationReason::MaxOps)
+            } else if start.elapsed() >= self.time_limit {
+                Some(TerminationReason::TimeLimit)
+            } else {
+                None
+            };
+            if let Some(reason) = limit {
+                self.termination = Some(if self.tree.size_hint().1 == Some(0) {
+                    TerminationReason::Exhausted
+                } else {
+                    reason
+                });
+                return None;
+            }
+            let Some(n) = self.tree.next() else {
+                self.termination = Some(TerminationReason::Exhausted);
+                return None;
+            };
+            let i = self.n_visited;
+            self.n_visited += 1;
+
+            if !(self.leaf_check_fn)(&n) {
+                continue;
+            }
+            if let Some(cost) = (self.cost_fn)(&n)
+                && self.best_cost.is_none_or(|c| c > cost)
+            {
+                self.best_cost = Some(cost);
+                let improvement = Improvement {
+                    cost,
+                    op_index: i,
+                    elapsed: start.elapsed(),
+                };
+                return Some((improvement, n));
+            }
+        }
+    }
+}
+
+impl<I, N, FL, FC, C> FusedIterator for ImprovingLeaves<I, FL, FC, C>
+where
+    I: FusedIterator<Item = N>,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+    C: Ord + Copy,
+{
+}
+
+/// Creates an iterator over the leaves of the tree that strictly improve on every leaf found before.
+///
+/// This function visits the tree like [`traverse`](super::traverse), with the same limits, but
+/// yields each new best leaf as soon as it is found instead of collecting them, so that the caller
+/// can show progressively better solutions while the search goes on. The time limit counts from
+/// the first call to `next`. Once the iterator returns `None`, [`ImprovingLeaves::termination`]
+/// tells why.
+///
+/// # Parameters
+/// - `tree`: A fused iterator over the tree nodes, or a mutable reference to one.
+/// - `leaf_check_fn`: A function that checks if a node is a leaf.
+/// - `cost_fn`: A function that computes the cost of a node, returning `None` if the cost cannot be determined.
+/// - `max_ops`: The maximum number of nodes to process.
+/// - `time_limit`: The maximum time allowed for the traversal.
+///
+/// # Returns
+/// An iterator of the improvements of the best leaf, with their cost, operation index and elapsed
+/// time, along with the leaves themselves. The last item is the best leaf found.
+pub fn improving_leaves<I, N, FL, FC, C>(
+    tree: I,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    max_ops: usize,
+    time_limit: Duration,
+) -> ImprovingLeaves<I, FL, FC, C>
+where
+    I: FusedIterator<Item = N>,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+    C: Ord + Copy,
+{
+    ImprovingLeaves {
+        tree,
+        leaf_check_fn,
+        cost_fn,
+        max_ops,
+        time_limit,
+        start: None,
+        n_visited: 0,
+        best_cost: None,
+        termination: None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::improving_leaves;
+    use crate::traversal::functional::{TerminationReason, dfs_reach, traverse_with_outcome};
+
+    type Node = Vec<bool>;
+
+    #[test]
+    fn test_improving_leaves() {
+        let weights = [3, 4, 6, 5];
+        let profits = [2, 3, 2, 4];
+        let capacity = 8;
+        let total_items = weights.len();
+
+        let total = |n: &Node, values: &[u32]| -> u32 {
+            n.iter()
+                .copied()
+                .enumerate()
+                .map(|(i, b)| if b { values[i] } else { 0 })
+                .sum()
+        };
+
+        let successor_fn = |n: &Node| {
+            if n.len() == total_items {
+                return vec![];
+            }
+
+            let mut children = vec![];
+
+            let mut c1 = n.clone();
+            c1.push(false);
+            children.push(c1);
+
+            let next_idx = n.len();
+            if capacity >= total(n, &weights) + weights[next_idx] {
+                let mut c2 = n.clone();
+                c2.push(true);
+                children.push(c2);
+            }
+
+            children
+        };
+        let cost_fn = |n: &Node| Some(u32::MAX - total(n, &profits));
+        let leaf_check_fn = |n: &Node| n.len() == total_items;
+
+        let mut leaves = improving_leaves(
+            dfs_reach(vec![], successor_fn),
+            leaf_check_fn,
+            cost_fn,
+            usize::MAX,
+            Duration::from_secs(10),
+        );
+        let improvements: Vec<_> = leaves.by_ref().collect();
+        assert_eq!(leaves.termination(), Some(TerminationReason::Exhausted));
+        assert!(
+            improvements
+                .windows(2)
+                .all(|w| w[0].0.cost > w[1].0.cost && w[0].0.op_index < w[1].0.op_index)
+        );
+
+        let outcome = traverse_with_outcome(
+            &mut dfs_reach(vec![], successor_fn),
+            leaf_check_fn,
+            cost_fn,
+            usize::MAX,
+            Duration::from_secs(10),
+            1,
+            |_, _| {},
+        );
+        let (cost, node) = outcome.best().expect("DFS should find a valid solution");
+        let (last, last_node) = improvements.last().expect("should find an improving leaf");
+        assert_eq!(last.cost, *cost);
+        assert_eq!(last_node, node);
+        assert_eq!(leaves.n_visited(), outcome.n_visited);
+        assert_eq!(
+            improvements
+                .iter()
+                .map(|(imp, _)| imp.op_index)
+                .collect::<Vec<_>>(),
+            outcome
+                .improvements
+                .iter()
+                .map(|imp| imp.op_index)
+                .collect::<Vec<_>>()
+        );
+
+        let mut leaves = improving_leaves(
+            dfs_reach(vec![], successor_fn),
+            leaf_check_fn,
+            cost_fn,
+            3,
+            Duration::from_secs(10),
+        );
+        assert!(leaves.by_ref().all(|(imp, _)| imp.op_index < 3));
+        assert_eq!(leaves.termination(), Some(TerminationReason::MaxOps));
+        assert!(leaves.next().is_none());
+
+        // running out of nodes exactly at the limit is still exhaustion
+        let mut leaves = improving_leaves(
+            dfs_reach(vec![], successor_fn),
+            leaf_check_fn,
+            cost_fn,
+            outcome.n_visited,
+            Duration::from_secs(10),
+        );
+        leaves.by_ref().for_each(drop);
+        assert_eq!(leaves.termination(), Some(TerminationReason::Exhausted));
+    }
+}
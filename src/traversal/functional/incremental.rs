@@ -0,0 +1,481 @@
+//! Incremental (do/undo) Search
+
+use std::time::{Duration, Instant};
+
+/// The operation and time limits of an incremental search.
+struct Budget {
+    n_ops: usize,
+    max_ops: usize,
+    start: Instant,
+    time_limit: Duration,
+}
+
+impl Budget {
+    fn new(max_ops: usize, time_limit: Duration) -> Self {
+        Self {
+            n_ops: 0,
+            max_ops,
+            start: Instant::now(),
+            time_limit,
+        }
+    }
+
+    /// Counts one operation, and returns `false` if a limit has been reached.
+    fn tick(&mut self) -> bool {
+        if self.n_ops >= self.max_ops || self.start.elapsed() >= self.time_limit {
+            return false;
+        }
+        self.n_ops += 1;
+        true
+    }
+}
+
+/// A node of the current path: its remaining moves, and the move that led to it.
+struct Frame<M, I> {
+    moves: I,
+    rank: usize,
+    discrepancy: usize,
+    applied: Option<M>,
+}
+
+/// What to do with the current state after visiting it.
+enum Visit {
+    Stop,
+    Backtrack,
+    Expand,
+}
+
+/// The functions defining an incremental search and the best leaf found so far.
+struct InPlace<N, C, FM, FA, FU, FL, FC> {
+    moves_fn: FM,
+    apply_fn: FA,
+    undo_fn: FU,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    best: Option<(C, N)>,
+}
+
+impl<N, M, IM, C, FM, FA, FU, FL, FC> InPlace<N, C, FM, FA, FU, FL, FC>
+where
+    N: Clone,
+    C: Ord + Copy,
+    IM: IntoIterator<Item = M>,
+    FM: Fn(&N) -> IM,
+    FA: Fn(&mut N, &M),
+    FU: Fn(&mut N, &M),
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+{
+    fn new(moves_fn: FM, apply_fn: FA, undo_fn: FU, leaf_check_fn: FL, cost_fn: FC) -> Self {
+        Self {
+            moves_fn,
+            apply_fn,
+            undo_fn,
+            leaf_check_fn,
+            cost_fn,
+            best: None,
+        }
+    }
+
+    /// Visits the current state. Only counted visits use the budget and evaluate leaves.
+    fn visit(
+        &mut self,
+        state: &N,
+        counted: bool,
+        budget: &mut Budget,
+        expand_fn: &mut impl FnMut(&N, Option<C>) -> bool,
+    ) -> Visit {
+        if counted && !budget.tick() {
+            return Visit::Stop;
+        }
+        let best_cost = self.best.as_ref().map(|(c, _)| *c);
+        if (self.leaf_check_fn)(state) {
+            if counted
+                && let Some(cost) = (self.cost_fn)(state)
+                && best_cost.is_none_or(|c| cost < c)
+            {
+                self.best = Some((cost, state.clone()));
+            }
+            return Visit::Backtrack;
+        }
+        if expand_fn(state, best_cost) {
+            Visit::Expand
+        } else {
+            Visit::Backtrack
+        }
+    }
+
+    /// Explores the tree below `state` depth-first, applying and undoing moves in place.
+    ///
+    /// With `Some(k)` as `discrepancy`, only paths with at most `k` discrepancies are followed,
+    /// and only the nodes with exactly `k` are counted. Returns `None` if a limit was reached,
+    /// otherwise whether some path was cut because of its discrepancy.
+    fn search(
+        &mut self,
+        state: &mut N,
+        discrepancy: Option<usize>,
+        budget: &mut Budget,
+        mut expand_fn: impl FnMut(&N, Option<C>) -> bool,
+    ) -> Option<bool> {
+        let counted = |d: usize| discrepancy.is_none_or(|k| d == k);
+        let mut cut = false;
+        let mut stack = vec![];
+
+        match self.visit(state, counted(0), budget, &mut expand_fn) {
+            Visit::Stop => return None,
+            Visit::Backtrack => return Some(cut),
+            Visit::Expand => stack.push(Frame {
+                moves: (self.moves_fn)(state).into_iter(),
+                rank: 0,
+                discrepancy: 0,
+                applied: None,
+            }),
+        }
+
+        while let Some(frame) = stack.last_mut() {
+            let Some(m) = frame.moves.next() else {
+                if let Some(m) = stack.pop().and_then(|f| f.applied) {
+                    (self.undo_fn)(state, &m);
+                }
+                continue;
+            };
+            // following any move but the first one is a discrepancy
+            let d = frame.discrepancy + usize::from(frame.rank > 0);
+            frame.rank += 1;
+            if discrepancy.is_some_and(|k| d > k) {
+                cut = true;
+                continue;
+            }
+
+            (self.apply_fn)(state, &m);
+            match self.visit(state, counted(d), budget, &mut expand_fn) {
+                Visit::Stop => return None,
+                Visit::Backtrack => (self.undo_fn)(state, &m),
+                Visit::Expand => stack.push(Frame {
+                    moves: (self.moves_fn)(state).into_iter(),
+                    rank: 0,
+                    discrepancy: d,
+                    applied: Some(m),
+                }),
+            }
+        }
+
+        Some(cut)
+    }
+}
+
+/// Find the leaf node with the lowest cost by using Depth First Search on a single state modified in place
+///
+/// - `start` is the start state.
+/// - `moves_fn` returns the list of moves from a given state, explored in the given order.
+/// - `apply_fn` applies a move to the state.
+/// - `undo_fn` reverts a move previously applied to the state.
+/// - `leaf_check_fn` check if a state is a leaf or not
+/// - `cost_fn` returns the final cost of a leaf state
+/// - `max_ops` is the maximum number of search operations to perform
+/// - `time_limit` is the maximum duration allowed for the search operation
+///
+/// Instead of generating child nodes, the state is moved down the tree with `apply_fn` and back up
+/// with `undo_fn`, so it is only cloned when a new best leaf is found.
+/// This function returns Some of a tuple of (cost, leaf state) if found, otherwise returns None.
+#[allow(clippy::too_many_arguments)]
+pub fn incremental_dfs<N, M, IM, C, FM, FA, FU, FL, FC>(
+    start: N,
+    moves_fn: FM,
+    apply_fn: FA,
+    undo_fn: FU,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    max_ops: usize,
+    time_limit: Duration,
+) -> Option<(C, N)>
+where
+    N: Clone,
+    C: Ord + Copy,
+    IM: IntoIterator<Item = M>,
+    FM: Fn(&N) -> IM,
+    FA: Fn(&mut N, &M),
+    FU: Fn(&mut N, &M),
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+{
+    let mut state = start;
+    let mut search = InPlace::new(moves_fn, apply_fn, undo_fn, leaf_check_fn, cost_fn);
+    let mut budget = Budget::new(max_ops, time_limit);
+    search.search(&mut state, None, &mut budget, |_, _| true);
+    search.best
+}
+
+/// Find the leaf node with the lowest cost by using Branch and Bound on a single state modified in place
+///
+/// - `start` is the start state.
+/// - `moves_fn` returns the list of moves from a given state, explored in the given order.
+/// - `apply_fn` applies a move to the state.
+/// - `undo_fn` reverts a move previously applied to the state.
+/// - `leaf_check_fn` check if a state is a leaf or not
+/// - `cost_fn` returns the final cost of a leaf state
+/// - `lower_bound_fn` returns the lower bound of a given state to decide whether to search deeper or not
+/// - `max_ops` is the maximum number of search operations to perform
+/// - `time_limit` is the maximum duration allowed for the search operation
+///
+/// This works like [`bbs`](super::bbs) but, as in [`incremental_dfs`], the state is only cloned
+/// when a new best leaf is found.
+/// This function returns Some of a tuple of (cost, leaf state) if found, otherwise returns None.
+#[allow(clippy::too_many_arguments)]
+pub fn incremental_bbs<N, M, IM, C, FM, FA, FU, FL, FC, FC2>(
+    start: N,
+    moves_fn: FM,
+    apply_fn: FA,
+    undo_fn: FU,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+    max_ops: usize,
+    time_limit: Duration,
+) -> Option<(C, N)>
+where
+    N: Clone,
+    C: Ord + Copy,
+    IM: IntoIterator<Item = M>,
+    FM: Fn(&N) -> IM,
+    FA: Fn(&mut N, &M),
+    FU: Fn(&mut N, &M),
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+    FC2: Fn(&N) -> Option<C>,
+{
+    let mut state = start;
+    let mut search = InPlace::new(moves_fn, apply_fn, undo_fn, leaf_check_fn, cost_fn);
+    let mut budget = Budget::new(max_ops, time_limit);
+    search.search(&mut state, None, &mut budget, |n, best_cost| {
+        lower_bound_fn(n).is_some_and(|lb| best_cost.is_none_or(|c| c > lb))
+    });
+    search.best
+}
+
+/// Find the leaf node with the lowest cost by using Limited Discrepancy Search on a single state modified in place
+///
+/// - `start` is the start state.
+/// - `moves_fn` returns the list of moves from a given state, best ranked first.
+/// - `apply_fn` applies a move to the state.
+/// - `undo_fn` reverts a move previously applied to the state.
+/// - `leaf_check_fn` check if a state is a leaf or not
+/// - `cost_fn` returns the final cost of a leaf state
+/// - `max_discrepancy` is the maximum number of discrepancies allowed along a path
+/// - `max_ops` is the maximum number of search operations to perform
+/// - `time_limit` is the maximum duration allowed for the search operation
+///
+/// Following any move but the first one is a discrepancy. Like [`lds`](super::lds), paths are
+/// explored in order of increasing number of discrepancies, by iterating over the number of
+/// discrepancies allowed. Each node is counted once, in the iteration matching its discrepancy,
+/// but the moves leading to it are applied again in later iterations.
+/// This function returns Some of a tuple of (cost, leaf state) if found, otherwise returns None.
+#[allow(clippy::too_many_arguments)]
+pub fn incremental_lds<N, M, IM, C, FM, FA, FU, FL, FC>(
+    start: N,
+    moves_fn: FM,
+    apply_fn: FA,
+    undo_fn: FU,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    max_discrepancy: usize,
+    max_ops: usize,
+    time_limit: Duration,
+) -> Option<(C, N)>
+where
+    N: Clone,
+    C: Ord + Copy,
+    IM: IntoIterator<Item = M>,
+    FM: Fn(&N) -> IM,
+    FA: Fn(&mut N, &M),
+    FU: Fn(&mut N, &M),
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+{
+    let mut state = start;
+    let mut search = InPlace::new(moves_fn, apply_fn, undo_fn, leaf_check_fn, cost_fn);
+    let mut budget = Budget::new(max_ops, time_limit);
+    for k in 0..=max_discrepancy {
+        // stop when a limit is reached, or when no path has more than k discrepancies
+        if search.search(&mut state, Some(k), &mut budget, |_, _| true) != Some(true) {
+            break;
+        }
+    }
+    search.best
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{incremental_bbs, incremental_dfs, incremental_lds};
+    use crate::traversal::functional::{bbs, gds};
+
+    #[derive(Debug, Clone)]
+    struct State {
+        items: Vec<bool>,
+        weight: u32,
+        profit: u32,
+    }
+
+    #[test]
+    fn test_incremental() {
+        let weights = [9, 3, 5, 1, 4, 2, 6, 3, 4, 2];
+        let profits = [20, 12, 20, 3, 15, 7, 22, 9, 14, 6];
+        let capacity = 15;
+        let total_items = weights.len();
+
+        // taking the next item is ranked first
+        let moves_fn = |s: &State| {
+            let i = s.items.len();
+            if i == total_items {
+                return vec![];
+            }
+            if s.weight + weights[i] <= capacity {
+                vec![true, false]
+            } else {
+                vec![false]
+            }
+        };
+        let apply_fn = |s: &mut State, &take: &bool| {
+            let i = s.items.len();
+            if take {
+                s.weight += weights[i];
+                s.profit += profits[i];
+            }
+            s.items.push(take);
+        };
+        let undo_fn = |s: &mut State, &take: &bool| {
+            s.items.pop();
+            let i = s.items.len();
+            if take {
+                s.weight -= weights[i];
+                s.profit -= profits[i];
+            }
+        };
+        let leaf_check_fn = |s: &State| s.items.len() == total_items;
+        let cost_fn = |s: &State| Some(u32::MAX - s.profit);
+        let lower_bound_fn = |s: &State| {
+            let max_remained_profit: u32 = profits[s.items.len()..].iter().sum();
+            Some(u32::MAX - (s.profit + max_remained_profit))
+        };
+        let start = State {
+            items: vec![],
+            weight: 0,
+            profit: 0,
+        };
+
+        // the same problem with cloned child nodes
+        let successor_fn = |s: &State| {
+            moves_fn(s)
+                .into_iter()
+                .map(|m| {
+                    let mut c = s.clone();
+                    apply_fn(&mut c, &m);
+                    c
+                })
+                .collect::<Vec<_>>()
+        };
+        let time_limit = Duration::from_secs(10);
+        let (optimal_cost, _) = bbs(
+            start.clone(),
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            usize::MAX,
+            time_limit,
+        )
+        .expect("BBS should find a valid solution");
+        let (greedy_cost, _) = gds(
+            start.clone(),
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            |s: &State| Some(s.profit),
+            usize::MAX,
+            time_limit,
+        )
+        .expect("GDS should find a valid solution");
+        assert!(greedy_cost > optimal_cost);
+
+        let check = |result: Option<(u32, State)>| {
+            let (cost, state) = result.expect("a valid solution should be found");
+            let weight: u32 = (0..total_items)
+                .filter(|&i| state.items[i])
+                .map(|i| weights[i])
+                .sum();
+            assert_eq!(state.weight, weight);
+            assert!(weight <= capacity);
+            assert_eq!(u32::MAX - state.profit, cost);
+            cost
+        };
+
+        let cost = check(incremental_dfs(
+            start.clone(),
+            moves_fn,
+            apply_fn,
+            undo_fn,
+            leaf_check_fn,
+            cost_fn,
+            usize::MAX,
+            time_limit,
+        ));
+        assert_eq!(cost, optimal_cost);
+
+        let cost = check(incremental_bbs(
+            start.clone(),
+            moves_fn,
+            apply_fn,
+            undo_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            usize::MAX,
+            time_limit,
+        ));
+        assert_eq!(cost, optimal_cost);
+
+        // without any discrepancy, only the greedy path is explored
+        let cost = check(incremental_lds(
+            start.clone(),
+            moves_fn,
+            apply_fn,
+            undo_fn,
+            leaf_check_fn,
+            cost_fn,
+            0,
+            usize::MAX,
+            time_limit,
+        ));
+        assert_eq!(cost, greedy_cost);
+
+        let cost = check(incremental_lds(
+            start.clone(),
+            moves_fn,
+            apply_fn,
+            undo_fn,
+            leaf_check_fn,
+            cost_fn,
+            total_items,
+            usize::MAX,
+            time_limit,
+        ));
+        assert_eq!(cost, optimal_cost);
+
+        // the first leaf is only reached after visiting the root and one node per item
+        assert!(
+            incremental_dfs(
+                start,
+                moves_fn,
+                apply_fn,
+                undo_fn,
+                leaf_check_fn,
+                cost_fn,
+                total_items,
+                time_limit,
+            )
+            .is_none()
+        );
+    }
+}
@@ -0,0 +1,286 @@
+//! Incumbents
+
+use std::{
+    cell::Cell,
+    fmt,
+    sync::{
+        Arc, Mutex, MutexGuard,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+/// A trait representing the cost of the best leaf found so far (the incumbent), used as an upper
+/// bound to prune branches.
+///
+/// [`BranchAndBoundContainer`](super::BranchAndBoundContainer) reads the incumbent before
+/// expanding a node and offers the cost of every leaf it finds. `Option<C>` is an incumbent local
+/// to one search, and [`SharedIncumbent`] is an incumbent shared by several searches.
+pub trait Incumbent<C> {
+    /// Returns the cost of the best leaf found so far, if any.
+    fn best_cost(&self) -> Option<C>;
+
+    /// Offers the cost of a new leaf, and returns `true` if it improves on the incumbent.
+    fn offer(&mut self, cost: C) -> bool;
+}
+
+impl<C: Ord + Copy> Incumbent<C> for Option<C> {
+    fn best_cost(&self) -> Option<C> {
+        *self
+    }
+
+    fn offer(&mut self, cost: C) -> bool {
+        if self.is_some_and(|c| c <= cost) {
+            return false;
+        }
+        *self = Some(cost);
+        true
+    }
+}
+
+/// The cost shared by the clones of a [`SharedIncumbent`], with a version incremented on every
+/// improvement.
+#[derive(Debug)]
+struct SharedCost<C> {
+    cost: Mutex<Option<C>>,
+    version: AtomicU64,
+}
+
+impl<C> SharedCost<C> {
+    fn lock(&self) -> MutexGuard<'_, Option<C>> {
+        self.cost.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// An incumbent shared by several searches, possibly running on different threads.
+///
+/// Clones share the same cost, so a leaf found by one search immediately tightens the upper bound
+/// of the others.
+///
+/// The cost works with any cost type because it is kept behind a mutex, but each clone caches the
+/// last cost it read together with a version of the shared cost, which is an atomic counter
+/// incremented on every improvement. Reading the incumbent, as done before every expansion, is
+/// therefore a single atomic load as long as the cost has not changed, and offering a leaf that
+/// is not better than the cached cost does not touch the shared state at all. The mutex is only
+/// locked to record an improvement and, once per improvement, by each clone refreshing its cache.
+/// Because of this cache, a `SharedIncumbent` can be sent to another thread but not shared
+/// between threads: give each thread its own clone.
+pub struct SharedIncumbent<C> {
+    shared: Arc<SharedCost<C>>,
+    cache: Cell<(u64, Option<C>)>,
+}
+
+impl<C> Clone for SharedIncumbent<C> {
+    fn clone(&self) -> Self {
+        Self::from_shared(Arc::clone(&self.shared))
+    }
+}
+
+impl<C> SharedIncumbent<C> {
+    fn from_shared(shared: Arc<SharedCost<C>>) -> Self {
+        // versions start at 1, so the first read refreshes the cache
+        Self {
+            shared,
+            cache: Cell::new((0, None)),
+        }
+    }
+}
+
+impl<C: Ord + Copy> SharedIncumbent<C> {
+    /// Creates a new `SharedIncumbent` without any cost.
+    pub fn new() -> Self {
+        Self::from_shared(Arc::new(SharedCost {
+            cost: Mutex::new(None),
+            version: AtomicU64::new(1),
+        }))
+    }
+
+    /// Creates a new `SharedIncumbent` seeded with an upper bound, e.g. the cost of a heuristic
+    /// solution, so that only nodes whose lower bound is better than it are expanded.
+    ///
+    /// The upper bound prunes nodes, not leaves: a leaf reached through a promising node is
+    /// yielded even if its cost is not better than the upper bound.
+    pub fn with_upper_bound(upper_bound: C) -> Self {
+        Self::from_shared(Arc::new(SharedCost {
+            cost: Mutex::new(Some(upper_bound)),
+            version: AtomicU64::new(1),
+        }))
+    }
+}
+
+impl<C: Ord + Copy> Default for SharedIncumbent<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Copy + fmt::Debug> fmt::Debug for SharedIncumbent<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedIncumbent")
+            .field("cost", &*self.shared.lock())
+            .finish()
+    }
+}
+
+impl<C: Ord + Copy> Incumbent<C> for SharedIncumbent<C> {
+    fn best_cost(&self) -> Option<C> {
+        let (version, cost) = self.cache.get();
+        if self.shared.version.load(Ordering::Acquire) == version {
+            return cost;
+        }
+        // the version only changes while the mutex is locked
+        let cost = *self.shared.lock();
+        let version = self.shared.version.load(Ordering::Relaxed);
+        self.cache.set((version, cost));
+        cost
+    }
+
+    fn offer(&mut self, cost: C) -> bool {
+        // the shared cost is never worse than the cached one
+        if self.cache.get().1.is_some_and(|c| c <= cost) {
+            return false;
+        }
+        let mut shared_cost = self.shared.lock();
+        let improved = shared_cost.offer(cost);
+        if improved {
+            self.shared.version.fetch_add(1, Ordering::Release);
+        }
+        let version = self.shared.version.load(Ordering::Relaxed);
+        self.cache.set((version, *shared_cost));
+        improved
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{Incumbent, SharedIncumbent};
+    use crate::traversal::functional::{bbs_incumbent_reach, bbs_reach, find_best};
+
+    type Node = Vec<bool>;
+
+    #[test]
+    fn test_incumbent() {
+        let weights = [2, 3, 5, 1, 4, 2];
+        let profits = [10, 12, 20, 3, 15, 7];
+        let capacity = 9;
+        let total_items = weights.len();
+
+        let total = |n: &Node, values: &[u32]| -> u32 {
+            n.iter()
+                .copied()
+                .enumerate()
+                .map(|(i, b)| if b { values[i] } else { 0 })
+                .sum()
+        };
+
+        let successor_fn = |n: &Node| {
+            if n.len() == total_items {
+                return vec![];
+            }
+
+            let mut children = vec![];
+
+            let next_idx = n.len();
+            if capacity >= total(n, &weights) + weights[next_idx] {
+                let mut c1 = n.clone();
+                c1.push(true);
+                children.push(c1);
+            }
+
+            let mut c2 = n.clone();
+            c2.push(false);
+            children.push(c2);
+
+            children
+        };
+
+        let lower_bound_fn = |n: &Node| {
+            let max_remained_profit: u32 = profits[n.len()..].iter().sum();
+            Some(u32::MAX - (total(n, &profits) + max_remained_profit))
+        };
+        let cost_fn = |n: &Node| Some(u32::MAX - total(n, &profits));
+        let leaf_check_fn = |n: &Node| n.len() == total_items;
+
+        let mut n_plain = 0;
+        let (optimal_cost, _) = find_best(
+            &mut bbs_reach(vec![], successor_fn, leaf_check_fn, cost_fn, lower_bound_fn),
+            leaf_check_fn,
+            cost_fn,
+            usize::MAX,
+            Duration::from_secs(10),
+            |_, _| n_plain += 1,
+        )
+        .expect("BBS should find a valid solution");
+        assert_eq!(u32::MAX - optimal_cost, 37);
+
+        // a heuristic solution one unit of profit worse than the optimum
+        let mut n_seeded = 0;
+        let (cost, _) = find_best(
+            &mut bbs_incumbent_reach(
+                vec![],
+                successor_fn,
+                leaf_check_fn,
+                cost_fn,
+                lower_bound_fn,
+                Some(optimal_cost + 1),
+            ),
+            leaf_check_fn,
+            cost_fn,
+            usize::MAX,
+            Duration::from_secs(10),
+            |_, _| n_seeded += 1,
+        )
+        .expect("BBS should find a solution better than the upper bound");
+        assert_eq!(cost, optimal_cost);
+        assert!(n_seeded < n_plain);
+
+        // leaves are not filtered by the upper bound: an unreachable one still lets leaves through
+        let best = find_best(
+            &mut bbs_incumbent_reach(
+                vec![],
+                successor_fn,
+                leaf_check_fn,
+                cost_fn,
+                lower_bound_fn,
+                Some(optimal_cost - 1),
+            ),
+            leaf_check_fn,
+            cost_fn,
+            usize::MAX,
+            Duration::from_secs(10),
+            |_, _| {},
+        );
+        assert!(best.is_some_and(|(c, _)| c > optimal_cost - 1));
+
+        // a second search sharing the incumbent of the first one prunes with its best cost
+        let incumbent = SharedIncumbent::new();
+        bbs_incumbent_reach(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            incumbent.clone(),
+        )
+        .for_each(drop);
+        assert_eq!(incumbent.best_cost(), Some(optimal_cost));
+
+        let n_shared = bbs_incumbent_reach(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            incumbent.clone(),
+        )
+        .count();
+        assert!(n_shared < n_plain);
+        assert!(!incumbent.clone().offer(optimal_cost));
+
+        // a clone sees the improvements offered through another one after caching the cost
+        let mut other = incumbent.clone();
+        assert!(other.offer(optimal_cost - 1));
+        assert_eq!(incumbent.best_cost(), Some(optimal_cost - 1));
+    }
+}
@@ -0,0 +1,498 @@
+//! Lazy Depth First Search and Branch and Bound Search
+
+use std::time::Duration;
+
+use super::{
+    common::{NodeContainer, Reachable},
+    find_best,
+};
+
+/// A container for Depth-First traversal generating the successors of a node one at a time.
+///
+/// Instead of the successors themselves, the stack holds the iterators over the successors of the
+/// nodes of the current path, and each successor is only generated when it is about to be visited.
+/// Unlike [`DepthFirstContainer`](super::DepthFirstContainer), successors are visited in the order
+/// they are generated.
+pub struct LazyDepthFirstContainer<N, FN, I> {
+    root: Option<N>,
+    stack: Vec<I>,
+    successor_fn: FN,
+}
+
+impl<N, FN, IN> LazyDepthFirstContainer<N, FN, IN::IntoIter>
+where
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    /// Creates a new `LazyDepthFirstContainer` with the given successor function.
+    pub fn new(start: N, successor_fn: FN) -> Self {
+        Self {
+            root: Some(start),
+            stack: vec![],
+            successor_fn,
+        }
+    }
+}
+
+impl<N, FN, IN> NodeContainer for LazyDepthFirstContainer<N, FN, IN::IntoIter>
+where
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    type Node = N;
+
+    fn pop(&mut self) -> Option<Self::Node> {
+        if let Some(root) = self.root.take() {
+            return Some(root);
+        }
+        while let Some(successors) = self.stack.last_mut() {
+            if let Some(node) = successors.next() {
+                return Some(node);
+            }
+            self.stack.pop();
+        }
+        None
+    }
+
+    fn expand_and_push(&mut self, node: &Self::Node) {
+        self.stack.push((self.successor_fn)(node).into_iter());
+    }
+}
+
+/// A container for Branch-and-Bound traversal generating the successors of a node one at a time.
+///
+/// Like [`LazyDepthFirstContainer`], each successor is only generated when it is about to be
+/// visited, and successors whose lower bound is not better than the best leaf found so far are
+/// pruned as soon as they are generated. Like [`BranchAndBoundContainer`](super::BranchAndBoundContainer),
+/// leaves are never pruned by their lower bound, so every generated leaf is visited. When the
+/// successors of every node are generated in order of non-decreasing lower bound (see
+/// [`with_ordered_bounds`](Self::with_ordered_bounds)), the remaining siblings of a pruned
+/// successor are not generated at all.
+pub struct LazyBranchAndBoundContainer<C, N, FN, FL, FC, FC2, I> {
+    root: Option<N>,
+    stack: Vec<I>,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+    current_best_cost: Option<C>,
+    ordered_bounds: bool,
+}
+
+impl<C, N, IN, FN, FL, FC, FC2> LazyBranchAndBoundContainer<C, N, FN, FL, FC, FC2, IN::IntoIter>
+where
+    C: Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+    FC2: Fn(&N) -> Option<C>,
+{
+    /// Creates a new `LazyBranchAndBoundContainer` with the given parameters.
+    pub fn new(
+        start: N,
+        successor_fn: FN,
+        leaf_check_fn: FL,
+        cost_fn: FC,
+        lower_bound_fn: FC2,
+    ) -> Self {
+        Self {
+            root: Some(start),
+            stack: vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            current_best_cost: None,
+            ordered_bounds: false,
+        }
+    }
+
+    /// Declares that `successor_fn` generates the successors of every node in order of
+    /// non-decreasing lower bound, so that once a successor is pruned, its remaining siblings are
+    /// discarded without being generated.
+    pub fn with_ordered_bounds(mut self) -> Self {
+        self.ordered_bounds = true;
+        self
+    }
+}
+
+impl<C, N, FN, FL, FC, FC2, I> LazyBranchAndBoundContainer<C, N, FN, FL, FC, FC2, I> {
+    /// Returns `true` if there is no node left to visit, which means the best leaf found is optimal.
+    pub fn is_exhausted(&self) -> bool {
+        self.root.is_none() && self.stack.is_empty()
+    }
+}
+
+impl<C, N, IN, FN, FL, FC, FC2> NodeContainer
+    for LazyBranchAndBoundContainer<C, N, FN, FL, FC, FC2, IN::IntoIter>
+where
+    C: Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+    FC2: Fn(&N) -> Option<C>,
+{
+    type Node = N;
+
+    fn pop(&mut self) -> Option<Self::Node> {
+        if let Some(root) = self.root.take() {
+            return Some(root);
+        }
+        while let Some(successors) = self.stack.last_mut() {
+            let Some(node) = successors.next() else {
+                self.stack.pop();
+                continue;
+            };
+            if (self.leaf_check_fn)(&node) {
+                return Some(node);
+            }
+            match (self.lower_bound_fn)(&node) {
+                Some(lb) if self.current_best_cost.is_none_or(|c| c > lb) => return Some(node),
+                // an infeasible node says nothing about the bounds of its siblings
+                None => {}
+                Some(_) if self.ordered_bounds => {
+                    self.stack.pop();
+                }
+                Some(_) => {}
+            }
+        }
+        None
+    }
+
+    fn expand_and_push(&mut self, node: &Self::Node) {
+        if (self.leaf_check_fn)(node) {
+            if let Some(cost) = (self.cost_fn)(node)
+                && self.current_best_cost.is_none_or(|c| cost < c)
+            {
+                self.current_best_cost = Some(cost);
+            }
+            return;
+        }
+        self.stack.push((self.successor_fn)(node).into_iter());
+    }
+}
+
+/// Creates a Depth-First Search traversal iterator generating the successors of a node one at a time.
+///
+/// This function works like [`dfs_reach`](super::dfs_reach), but only keeps an iterator over the
+/// successors of each node of the current path, and visits the successors in the order they are
+/// generated. A lazy `successor_fn`, e.g. returning a `map` over the possible decisions, then only
+/// generates the successors that are actually visited.
+///
+/// # Parameters
+/// - `start`: The root node from which to begin the traversal.
+/// - `successor_fn`: A function that, given a node, returns an iterator over its successor nodes.
+///
+/// # Returns
+/// An iterator that yields nodes reachable from the start node in depth-first order.
+pub fn lazy_dfs_reach<N, IN, FN>(
+    start: N,
+    successor_fn: FN,
+) -> Reachable<LazyDepthFirstContainer<N, FN, IN::IntoIter>>
+where
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+{
+    let container = LazyDepthFirstContainer::new(start, successor_fn);
+    Reachable::new(container)
+}
+
+/// Find the leaf node with the lowest cost by using Depth First Search generating successors one at a time
+///
+/// - `start` is the start node.
+/// - `successor_fn` returns an iterator over the successors of a given node.
+/// - `leaf_check_fn` check if a node is leaf or not
+/// - `cost_fn` returns the final cost of a leaf node
+/// - `max_ops` is the maximum number of search operations to perform
+/// - `time_limit` is the maximum duration allowed for the search operation
+///
+/// This function returns Some of a tuple of (cost, leaf node) if found, otherwise returns None.
+pub fn lazy_dfs<N, IN, FN, FC, C, FR>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FR,
+    cost_fn: FC,
+    max_ops: usize,
+    time_limit: Duration,
+) -> Option<(C, N)>
+where
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    FC: Fn(&N) -> Option<C>,
+    C: Ord + Copy,
+    FR: Fn(&N) -> bool,
+{
+    let mut res = lazy_dfs_reach(start, successor_fn);
+    find_best(
+        &mut res,
+        leaf_check_fn,
+        cost_fn,
+        max_ops,
+        time_limit,
+        |_, _| {},
+    )
+}
+
+/// Creates a Branch-and-Bound traversal iterator generating the successors of a node one at a time.
+///
+/// This function works like [`bbs_reach`](super::bbs_reach), but successors are generated and
+/// checked against the best leaf found so far one at a time, right before being visited. Leaves
+/// are visited without checking their lower bound. See
+/// [`lazy_bbs_ordered_reach`] to also skip the siblings of pruned successors.
+///
+/// # Parameters
+/// - `start`: The root node from which to begin the traversal.
+/// - `successor_fn`: A function that, given a node, returns an iterator over its successor nodes.
+/// - `leaf_check_fn`: A function that checks if a node is a leaf.
+/// - `cost_fn`: A function that returns the cost of a leaf node, or `None` if it is infeasible.
+/// - `lower_bound_fn`: A function that returns a lower bound of the cost of the leaves below a node.
+///
+/// # Returns
+/// An iterator that yields the nodes visited by the branch-and-bound search.
+#[allow(clippy::type_complexity)]
+pub fn lazy_bbs_reach<C, N, IN, FN, FL, FC, FC2>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+) -> Reachable<LazyBranchAndBoundContainer<C, N, FN, FL, FC, FC2, IN::IntoIter>>
+where
+    C: Ord + Copy,
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+    FC2: Fn(&N) -> Option<C>,
+{
+    let container = LazyBranchAndBoundContainer::new(
+        start,
+        successor_fn,
+        leaf_check_fn,
+        cost_fn,
+        lower_bound_fn,
+    );
+    Reachable::new(container)
+}
+
+/// Creates a Branch-and-Bound traversal iterator for successors generated in order of non-decreasing lower bound.
+///
+/// This function works like [`lazy_bbs_reach`], but once a successor is pruned, its remaining
+/// siblings, whose lower bounds are not better, are discarded without being generated.
+/// `successor_fn` must generate the successors of every node in order of non-decreasing lower
+/// bound, otherwise better leaves may be missed.
+///
+/// # Parameters
+/// - `start`: The root node from which to begin the traversal.
+/// - `successor_fn`: A function that, given a node, returns an iterator over its successor nodes, by non-decreasing
+///   lower bound.
+/// - `leaf_check_fn`: A function that checks if a node is a leaf.
+/// - `cost_fn`: A function that returns the cost of a leaf node, or `None` if it is infeasible.
+/// - `lower_bound_fn`: A function that returns a lower bound of the cost of the leaves below a node.
+///
+/// # Returns
+/// An iterator that yields the nodes visited by the branch-and-bound search.
+#[allow(clippy::type_complexity)]
+pub fn lazy_bbs_ordered_reach<C, N, IN, FN, FL, FC, FC2>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+) -> Reachable<LazyBranchAndBoundContainer<C, N, FN, FL, FC, FC2, IN::IntoIter>>
+where
+    C: Ord + Copy,
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+    FC2: Fn(&N) -> Option<C>,
+{
+    let container = LazyBranchAndBoundContainer::new(
+        start,
+        successor_fn,
+        leaf_check_fn,
+        cost_fn,
+        lower_bound_fn,
+    )
+    .with_ordered_bounds();
+    Reachable::new(container)
+}
+
+/// Find the leaf node with the lowest cost by using Branch and Bound generating successors one at a time
+///
+/// - `start` is the start node.
+/// - `successor_fn` returns an iterator over the successors of a given node.
+/// - `leaf_check_fn` check if a node is leaf or not
+/// - `cost_fn` returns the final cost of a leaf node
+/// - `lower_bound_fn` returns the lower bound of a given node to decide whether to search deeper or not
+/// - `max_ops` is the maximum number of search operations to perform
+/// - `time_limit` is the maximum duration allowed for the search operation
+///
+/// This function returns Some of a tuple of (cost, leaf node) if found, otherwise returns None.
+pub fn lazy_bbs<C, N, IN, FN, FL, FC, FC2>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+    max_ops: usize,
+    time_limit: Duration,
+) -> Option<(C, N)>
+where
+    C: Ord + Copy,
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    FL: Copy + Fn(&N) -> bool,
+    FC: Copy + Fn(&N) -> Option<C>,
+    FC2: Fn(&N) -> Option<C>,
+{
+    let mut res = lazy_bbs_reach(start, successor_fn, leaf_check_fn, cost_fn, lower_bound_fn);
+    find_best(
+        &mut res,
+        leaf_check_fn,
+        cost_fn,
+        max_ops,
+        time_limit,
+        |_, _| {},
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::Cell, time::Duration};
+
+    use super::{lazy_bbs, lazy_bbs_ordered_reach, lazy_dfs_reach};
+    use crate::traversal::functional::{bbs, dfs_reach, find_best};
+
+    type Node = Vec<u32>;
+
+    #[test]
+    fn test_lazy() {
+        // assign one of 6 values to each of 4 variables, minimizing the sum of squared deviations
+        // from the targets; the values are generated by increasing deviation
+        let targets = [3, 1, 4, 2];
+        let n_values = 6;
+        let n_generated = Cell::new(0);
+        let deviation = |i: usize, v: u32| v.abs_diff(targets[i]).pow(2);
+        let successor_fn = |n: &Node| {
+            let i = n.len();
+            let mut values: Vec<u32> = if i == targets.len() {
+                vec![]
+            } else {
+                (0..n_values).collect()
+            };
+            values.sort_by_key(|&v| deviation(i, v));
+            let n = n.clone();
+            let n_generated = &n_generated;
+            values.into_iter().map(move |v| {
+                n_generated.set(n_generated.get() + 1);
+                let mut c = n.clone();
+                c.push(v);
+                c
+            })
+        };
+        let leaf_check_fn = |n: &Node| n.len() == targets.len();
+        let cost_fn = |n: &Node| {
+            Some(
+                n.iter()
+                    .enumerate()
+                    .map(|(i, &v)| deviation(i, v))
+                    .sum::<u32>(),
+            )
+        };
+        let lower_bound_fn = cost_fn;
+
+        // the same nodes as DFS, in the order they are generated
+        let lazy: Vec<_> = lazy_dfs_reach(vec![], successor_fn).collect();
+        let eager: Vec<_> = dfs_reach(vec![], |n: &Node| {
+            let mut successors: Vec<_> = successor_fn(n).collect();
+            successors.reverse();
+            successors
+        })
+        .collect();
+        assert_eq!(lazy, eager);
+
+        n_generated.set(0);
+        let (expected_cost, _) = bbs(
+            vec![],
+            |n: &Node| successor_fn(n).collect::<Vec<_>>(),
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            usize::MAX,
+            Duration::from_secs(10),
+        )
+        .expect("BBS should find a valid solution");
+        let n_eager = n_generated.replace(0);
+
+        let (cost, _) = lazy_bbs(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            usize::MAX,
+            Duration::from_secs(10),
+        )
+        .expect("lazy BBS should find a valid solution");
+        assert_eq!(cost, expected_cost);
+        let n_lazy = n_generated.replace(0);
+
+        let (cost, best) = find_best(
+            &mut lazy_bbs_ordered_reach(
+                vec![],
+                successor_fn,
+                leaf_check_fn,
+                cost_fn,
+                lower_bound_fn,
+            ),
+            leaf_check_fn,
+            cost_fn,
+            usize::MAX,
+            Duration::from_secs(10),
+            |_, _| {},
+        )
+        .expect("lazy BBS should find a valid solution");
+        assert_eq!(cost, expected_cost);
+        assert_eq!(best, vec![3, 1, 4, 2]);
+        let n_ordered = n_generated.get();
+
+        assert!(n_lazy <= n_eager);
+        assert!(n_ordered < n_lazy);
+        // only the best value and its first sibling, which gets pruned, are generated per variable,
+        // except for the last one whose values are all leaves
+        assert_eq!(n_ordered, 2 * (targets.len() - 1) + n_values as usize);
+
+        // leaves are visited even without a lower bound, like BBS
+        let partial_lower_bound_fn = |n: &Node| {
+            if leaf_check_fn(n) {
+                None
+            } else {
+                lower_bound_fn(n)
+            }
+        };
+        let expected = bbs(
+            vec![],
+            |n: &Node| successor_fn(n).collect::<Vec<_>>(),
+            leaf_check_fn,
+            cost_fn,
+            partial_lower_bound_fn,
+            usize::MAX,
+            Duration::from_secs(10),
+        );
+        assert_eq!(expected, Some((0, vec![3, 1, 4, 2])));
+        let lazy = lazy_bbs(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            partial_lower_bound_fn,
+            usize::MAX,
+            Duration::from_secs(10),
+        );
+        assert_eq!(lazy, expected);
+    }
+}
@@ -0,0 +1,389 @@
+//! Limited Discrepancy Search
+
+use std::{cmp::Reverse, collections::BinaryHeap, time::Duration};
+
+use crate::utils::ScoredItem;
+
+use super::{
+    common::{NodeContainer, Reachable},
+    find_best,
+};
+
+/// The way discrepancies of a path are measured by a [`LimitedDiscrepancyContainer`].
+///
+/// A discrepancy is a decision that does not follow the best ranked child of a node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscrepancyKind {
+    /// Limited Discrepancy Search: a path is measured by the number of discrepancies along it.
+    Limited,
+    /// Depth-bounded Discrepancy Search: a path is measured by the depth of its deepest discrepancy.
+    DepthBounded,
+}
+
+/// A node with its depth, keyed by (discrepancy, insertion order).
+type DiscrepancyItem<N> = ScoredItem<(Reverse<usize>, usize), (usize, N)>;
+
+/// A container for Limited Discrepancy traversal.
+///
+/// Children are ranked by their priority and following any child but the best ranked one is a
+/// discrepancy. Paths are explored in order of increasing discrepancy (as measured by
+/// [`DiscrepancyKind`]), depth-first among paths with the same discrepancy, and paths whose
+/// discrepancy exceeds `max_discrepancy` are never explored. Every node is visited at most once.
+pub struct LimitedDiscrepancyContainer<N, FN, FP> {
+    to_see: BinaryHeap<DiscrepancyItem<N>>,
+    successor_fn: FN,
+    priority_fn: FP,
+    kind: DiscrepancyKind,
+    max_discrepancy: usize,
+    current: (usize, usize),
+    n_pushed: usize,
+}
+
+impl<N, IN, FN, FP, P> LimitedDiscrepancyContainer<N, FN, FP>
+where
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FP: Fn(&N) -> Option<P>,
+    P: Ord + Copy,
+{
+    /// Creates a new `LimitedDiscrepancyContainer` with the given parameters.
+    pub fn new(
+        start: N,
+        successor_fn: FN,
+        priority_fn: FP,
+        kind: DiscrepancyKind,
+        max_discrepancy: usize,
+    ) -> Self {
+        let mut container = Self {
+            to_see: BinaryHeap::new(),
+            successor_fn,
+            priority_fn,
+            kind,
+            max_discrepancy,
+            current: (0, 0),
+            n_pushed: 0,
+        };
+        container.push(0, 0, start);
+        container
+    }
+
+    /// Returns the discrepancy of the path to the most recently popped node.
+    pub fn current_discrepancy(&self) -> usize {
+        self.current.1
+    }
+
+    fn push(&mut self, depth: usize, discrepancy: usize, node: N) {
+        self.to_see.push(ScoredItem::from((
+            (Reverse(discrepancy), self.n_pushed),
+            (depth, node),
+        )));
+        self.n_pushed += 1;
+    }
+}
+
+impl<N, IN, FN, FP, P> NodeContainer for LimitedDiscrepancyContainer<N, FN, FP>
+where
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FP: Fn(&N) -> Option<P>,
+    P: Ord + Copy,
+{
+    type Node = N;
+
+    fn pop(&mut self) -> Option<Self::Node> {
+        let ((Reverse(discrepancy), _), (depth, node)) = self.to_see.pop()?.into_inner();
+        self.current = (depth, discrepancy);
+        Some(node)
+    }
+
+    fn expand_and_push(&mut self, node: &Self::Node) {
+        let (depth, discrepancy) = self.current;
+        let mut successors: Vec<_> = (self.successor_fn)(node)
+            .into_iter()
+            .filter_map(|s| (self.priority_fn)(&s).map(|priority| (priority, s)))
+            .collect();
+        successors.sort_by_key(|s| Reverse(s.0));
+
+        // push in reverse rank order so that better ranked children are popped first
+        for (rank, (_, s)) in successors.into_iter().enumerate().rev() {
+            let d = match (rank, self.kind) {
+                (0, _) => discrepancy,
+                (_, DiscrepancyKind::Limited) => discrepancy + 1,
+                (_, DiscrepancyKind::DepthBounded) => depth + 1,
+            };
+            if d <= self.max_discrepancy {
+                self.push(depth + 1, d, s);
+            }
+        }
+    }
+
+    fn frontier_size(&self) -> Option<usize> {
+        Some(self.to_see.len())
+    }
+}
+
+/// Creates a Limited Discrepancy Search traversal iterator starting from the given node.
+///
+/// This function initializes a lazy iterator that first follows the best ranked child at every
+/// node (like greedy search) and then explores paths with one, two, ... discrepancies, i.e.
+/// decisions that deviate from the best ranked child, up to `max_discrepancy`.
+/// With `max_discrepancy = 0` this is equivalent to greedy search, and with an unbounded
+/// `max_discrepancy` every node is eventually visited.
+///
+/// # Parameters
+/// - `start`: The root node from which to begin the traversal.
+/// - `successor_fn`: A function that, given a node, returns an iterator over its successor nodes.
+/// - `priority_fn`: A function that evaluates a node, returning `Some(priority)` where higher priorities
+///   are better, or `None` if the node cannot be evaluated (and thus skipped).
+/// - `max_discrepancy`: The maximum number of discrepancies allowed along a path.
+///
+/// # Returns
+/// An iterator that yields nodes reachable from the start node in limited discrepancy order.
+/// The iterator is lazy and will only compute successors as needed.
+pub fn lds_reach<N, IN, FN, FP, P>(
+    start: N,
+    successor_fn: FN,
+    priority_fn: FP,
+    max_discrepancy: usize,
+) -> Reachable<LimitedDiscrepancyContainer<N, FN, FP>>
+where
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FP: Fn(&N) -> Option<P>,
+    P: Ord + Copy,
+{
+    let container = LimitedDiscrepancyContainer::new(
+        start,
+        successor_fn,
+        priority_fn,
+        DiscrepancyKind::Limited,
+        max_discrepancy,
+    );
+    Reachable::new(container)
+}
+
+/// Creates a Depth-bounded Discrepancy Search traversal iterator starting from the given node.
+///
+/// This function initializes a lazy iterator that first follows the best ranked child at every
+/// node and then explores paths in order of increasing depth of their deepest discrepancy:
+/// paths deviating only at depth 1, then paths deviating anywhere up to depth 2, and so on up to
+/// `max_depth`. Below that depth only the best ranked child is followed.
+///
+/// # Parameters
+/// - `start`: The root node from which to begin the traversal.
+/// - `successor_fn`: A function that, given a node, returns an iterator over its successor nodes.
+/// - `priority_fn`: A function that evaluates a node, returning `Some(priority)` where higher priorities
+///   are better, or `None` if the node cannot be evaluated (and thus skipped).
+/// - `max_depth`: The maximum depth at which discrepancies are allowed.
+///
+/// # Returns
+/// An iterator that yields nodes reachable from the start node in depth-bounded discrepancy order.
+/// The iterator is lazy and will only compute successors as needed.
+pub fn dds_reach<N, IN, FN, FP, P>(
+    start: N,
+    successor_fn: FN,
+    priority_fn: FP,
+    max_depth: usize,
+) -> Reachable<LimitedDiscrepancyContainer<N, FN, FP>>
+where
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FP: Fn(&N) -> Option<P>,
+    P: Ord + Copy,
+{
+    let container = LimitedDiscrepancyContainer::new(
+        start,
+        successor_fn,
+        priority_fn,
+        DiscrepancyKind::DepthBounded,
+        max_depth,
+    );
+    Reachable::new(container)
+}
+
+/// Find the leaf node with the lowest cost by using Limited Discrepancy Search
+///
+/// - `start` is the start node.
+/// - `successor_fn` returns a list of successors for a given node.
+/// - `leaf_check_fn` checks if a node is a leaf or not
+/// - `cost_fn` returns the final cost of a leaf node
+/// - `priority_fn` returns the priority of a given node to rank the children of a node
+/// - `max_discrepancy` is the maximum number of discrepancies allowed along a path
+/// - `max_ops` sets the maximum number of search operations to perform.
+/// - `time_limit` sets the time limit for the search.
+///
+/// This function returns Some of a tuple of (cost, leaf node) if found, otherwise returns None
+#[allow(clippy::too_many_arguments)]
+pub fn lds<N, IN, FN, FC, FP, C, P, FR>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FR,
+    cost_fn: FC,
+    priority_fn: FP,
+    max_discrepancy: usize,
+    max_ops: usize,
+    time_limit: Duration,
+) -> Option<(C, N)>
+where
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    FC: Fn(&N) -> Option<C>,
+    FP: Fn(&N) -> Option<P>,
+    C: Ord + Copy,
+    P: Ord + Copy,
+    FR: Fn(&N) -> bool,
+{
+    let mut res = lds_reach(start, successor_fn, priority_fn, max_discrepancy);
+    find_best(
+        &mut res,
+        leaf_check_fn,
+        cost_fn,
+        max_ops,
+        time_limit,
+        |_, _| {},
+    )
+}
+
+/// Find the leaf node with the lowest cost by using Depth-bounded Discrepancy Search
+///
+/// - `start` is the start node.
+/// - `successor_fn` returns a list of successors for a given node.
+/// - `leaf_check_fn` checks if a node is a leaf or not
+/// - `cost_fn` returns the final cost of a leaf node
+/// - `priority_fn` returns the priority of a given node to rank the children of a node
+/// - `max_depth` is the maximum depth at which discrepancies are allowed
+/// - `max_ops` sets the maximum number of search operations to perform.
+/// - `time_limit` sets the time limit for the search.
+///
+/// This function returns Some of a tuple of (cost, leaf node) if found, otherwise returns None
+#[allow(clippy::too_many_arguments)]
+pub fn dds<N, IN, FN, FC, FP, C, P, FR>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FR,
+    cost_fn: FC,
+    priority_fn: FP,
+    max_depth: usize,
+    max_ops: usize,
+    time_limit: Duration,
+) -> Option<(C, N)>
+where
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    FC: Fn(&N) -> Option<C>,
+    FP: Fn(&N) -> Option<P>,
+    C: Ord + Copy,
+    P: Ord + Copy,
+    FR: Fn(&N) -> bool,
+{
+    let mut res = dds_reach(start, successor_fn, priority_fn, max_depth);
+    find_best(
+        &mut res,
+        leaf_check_fn,
+        cost_fn,
+        max_ops,
+        time_limit,
+        |_, _| {},
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{dds, lds};
+
+    type Node = Vec<bool>;
+
+    #[test]
+    fn test_lds() {
+        let weights = [3, 4, 6, 5];
+        let profits = [2, 3, 2, 4];
+        let capacity = 8;
+        let total_items = weights.len();
+
+        let successor_fn = |n: &Node| {
+            if n.len() == total_items {
+                return vec![];
+            }
+
+            let total_weight: u32 = n
+                .iter()
+                .copied()
+                .enumerate()
+                .map(|(i, b)| if b { weights[i] } else { 0 })
+                .sum();
+
+            let mut children = vec![];
+
+            let mut c1 = n.clone();
+            c1.push(false);
+            children.push(c1);
+
+            let next_idx = n.len();
+            if capacity >= total_weight + weights[next_idx] {
+                let mut c2 = n.clone();
+                c2.push(true);
+                children.push(c2);
+            }
+
+            children
+        };
+
+        let total_profit = |n: &Node| {
+            let s: u32 = n
+                .iter()
+                .copied()
+                .enumerate()
+                .map(|(i, b)| if b { profits[i] } else { 0 })
+                .sum();
+            s
+        };
+
+        let cost_fn = |n: &Node| Some(u32::MAX - total_profit(n));
+        let priority_fn = |n: &Node| Some(total_profit(n));
+        let leaf_check_fn = |n: &Node| n.len() == total_items;
+        let max_ops = usize::MAX;
+        let time_limit = Duration::from_secs(10);
+
+        let run_lds = |max_discrepancy| {
+            let (cost, best_node) = lds(
+                vec![],
+                successor_fn,
+                leaf_check_fn,
+                cost_fn,
+                priority_fn,
+                max_discrepancy,
+                max_ops,
+                time_limit,
+            )
+            .expect("LDS should find a valid solution");
+            (u32::MAX - cost, best_node)
+        };
+
+        // greedy path only
+        assert_eq!(run_lds(0), (5, vec![true, true, false, false]));
+        // skipping the second item is a single discrepancy
+        assert_eq!(run_lds(1), (6, vec![true, false, false, true]));
+
+        let run_dds = |max_depth| {
+            let (cost, best_node) = dds(
+                vec![],
+                successor_fn,
+                leaf_check_fn,
+                cost_fn,
+                priority_fn,
+                max_depth,
+                max_ops,
+                time_limit,
+            )
+            .expect("DDS should find a valid solution");
+            (u32::MAX - cost, best_node)
+        };
+
+        // a discrepancy on the first item does not help
+        assert_eq!(run_dds(1), (5, vec![true, true, false, false]));
+        // the discrepancy on the second item is at depth 2
+        assert_eq!(run_dds(2), (6, vec![true, false, false, true]));
+    }
+}
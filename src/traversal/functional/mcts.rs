@@ -0,0 +1,415 @@
+//! Monte Carlo Tree Search
+
+use std::time::Duration;
+
+use crate::{ToF64, utils::Rng};
+
+use super::{
+    common::{NodeContainer, Reachable},
+    find_best,
+};
+
+/// Statistics of a node in the search tree built by [`MonteCarloContainer`].
+struct TreeEntry<N> {
+    parent: Option<usize>,
+    children: Vec<usize>,
+    untried: Vec<N>,
+    visits: u32,
+    total_reward: f64,
+    exhausted: bool,
+}
+
+impl<N> TreeEntry<N> {
+    fn new(parent: Option<usize>) -> Self {
+        Self {
+            parent,
+            children: vec![],
+            untried: vec![],
+            visits: 0,
+            total_reward: 0.0,
+            exhausted: false,
+        }
+    }
+}
+
+/// A container for Monte Carlo Tree Search (UCT) traversal.
+///
+/// The container builds a search tree incrementally. Each [`pop`](NodeContainer::pop) selects a
+/// tree node by UCB1, expands one of its untried children, performs a uniformly random rollout from
+/// that child down to a leaf and returns the reached leaf. The following
+/// [`expand_and_push`](NodeContainer::expand_and_push) backpropagates the reward of that leaf,
+/// which is its cost normalized to `[0, 1]` over the costs seen so far (lower cost is better, and
+/// infeasible leaves get a reward of 0). Subtrees that have been fully explored are never selected
+/// again, and the traversal ends once the whole tree has been explored.
+pub struct MonteCarloContainer<C, N, FN, FL, FC> {
+    root: Option<N>,
+    tree: Vec<TreeEntry<N>>,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    exploration: f64,
+    rng: Rng,
+    cost_range: Option<(C, C)>,
+    pending: Option<usize>,
+}
+
+impl<C, N, IN, FN, FL, FC> MonteCarloContainer<C, N, FN, FL, FC>
+where
+    C: Ord + Copy + ToF64,
+    N: Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+{
+    /// Creates a new `MonteCarloContainer` with the given parameters.
+    pub fn new(
+        start: N,
+        successor_fn: FN,
+        leaf_check_fn: FL,
+        cost_fn: FC,
+        exploration: f64,
+        seed: u64,
+    ) -> Self {
+        Self {
+            root: Some(start),
+            tree: vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            exploration,
+            rng: Rng::new(seed),
+            cost_range: None,
+            pending: None,
+        }
+    }
+
+    fn ucb(&self, parent_visits: f64, idx: usize) -> f64 {
+        let entry = &self.tree[idx];
+        if entry.visits == 0 {
+            return f64::INFINITY;
+        }
+        let visits = f64::from(entry.visits);
+        entry.total_reward / visits + self.exploration * (parent_visits.ln() / visits).sqrt()
+    }
+
+    fn select_child(&self, idx: usize) -> Option<usize> {
+        let parent_visits = f64::from(self.tree[idx].visits);
+        self.tree[idx]
+            .children
+            .iter()
+            .copied()
+            .filter(|&c| !self.tree[c].exhausted)
+            .max_by(|&a, &b| {
+                self.ucb(parent_visits, a)
+                    .total_cmp(&self.ucb(parent_visits, b))
+            })
+    }
+
+    fn random_successor(&mut self, node: &N) -> Option<N> {
+        let mut successors: Vec<N> = (self.successor_fn)(node).into_iter().collect();
+        if successors.is_empty() {
+            return None;
+        }
+        let k = self.rng.gen_index(successors.len());
+        Some(successors.swap_remove(k))
+    }
+
+    fn reward(&mut self, node: &N) -> f64 {
+        if !(self.leaf_check_fn)(node) {
+            return 0.0;
+        }
+        let Some(cost) = (self.cost_fn)(node) else {
+            return 0.0;
+        };
+        let (min, max) = self
+            .cost_range
+            .map_or((cost, cost), |(min, max)| (min.min(cost), max.max(cost)));
+        self.cost_range = Some((min, max));
+        if min == max {
+            return 1.0;
+        }
+        let (min, max, cost) = (min.to_f64(), max.to_f64(), cost.to_f64());
+        (max - cost) / (max - min)
+    }
+}
+
+impl<C, N, IN, FN, FL, FC> NodeContainer for MonteCarloContainer<C, N, FN, FL, FC>
+where
+    C: Ord + Copy + ToF64,
+    N: Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+{
+    type Node = N;
+
+    fn pop(&mut self) -> Option<Self::Node> {
+        if let Some(root) = self.root.take() {
+            return Some(root);
+        }
+        if self.tree.first().is_none_or(|root| root.exhausted) {
+            return None;
+        }
+
+        // selection
+        let mut idx = 0;
+        while self.tree[idx].untried.is_empty() {
+            idx = self.select_child(idx)?;
+        }
+
+        // expansion
+        let k = self.rng.gen_index(self.tree[idx].untried.len());
+        let child = self.tree[idx].untried.swap_remove(k);
+        let child_idx = self.tree.len();
+        self.tree[idx].children.push(child_idx);
+        self.tree.push(TreeEntry::new(Some(idx)));
+        self.pending = Some(child_idx);
+
+        if (self.leaf_check_fn)(&child) {
+            self.tree[child_idx].exhausted = true;
+            return Some(child);
+        }
+        let untried: Vec<N> = (self.successor_fn)(&child).into_iter().collect();
+        if untried.is_empty() {
+            self.tree[child_idx].exhausted = true;
+            return Some(child);
+        }
+
+        // rollout
+        let mut current = untried[self.rng.gen_index(untried.len())].clone();
+        self.tree[child_idx].untried = untried;
+        while !(self.leaf_check_fn)(&current) {
+            match self.random_successor(&current) {
+                Some(next) => current = next,
+                None => break,
+            }
+        }
+        Some(current)
+    }
+
+    fn expand_and_push(&mut self, node: &Self::Node) {
+        let reward = self.reward(node);
+
+        let Some(mut idx) = self.pending.take() else {
+            // the root node has just been visited
+            let mut entry = TreeEntry::new(None);
+            if !(self.leaf_check_fn)(node) {
+                entry.untried = (self.successor_fn)(node).into_iter().collect();
+            }
+            entry.exhausted = entry.untried.is_empty();
+            self.tree.push(entry);
+            return;
+        };
+
+        // backpropagation
+        loop {
+            let entry = &self.tree[idx];
+            let exhausted = entry.exhausted
+                || (entry.untried.is_empty()
+                    && entry.children.iter().all(|&c| self.tree[c].exhausted));
+            let entry = &mut self.tree[idx];
+            entry.visits += 1;
+            entry.total_reward += reward;
+            entry.exhausted = exhausted;
+            match entry.parent {
+                Some(parent) => idx = parent,
+                None => break,
+            }
+        }
+    }
+}
+
+/// Creates a Monte Carlo Tree Search traversal iterator starting from the given node.
+///
+/// This function initializes a lazy iterator that yields the start node and then one node per
+/// MCTS iteration: the leaf reached by a random rollout (or a dead end if the rollout could not
+/// reach a leaf). Tree nodes are selected with the UCB1 rule using the given `exploration`
+/// constant, and the random choices are driven by a generator seeded with `seed`, so the
+/// traversal is reproducible.
+///
+/// # Parameters
+/// - `start`: The root node from which to begin the traversal.
+/// - `successor_fn`: A function that, given a node, returns an iterator over its successor nodes.
+/// - `leaf_check_fn`: A function that determines whether a given node is a leaf (terminal) node.
+/// - `cost_fn`: A function that computes the cost of a leaf node, returning `Some(cost)` if the cost
+///   can be determined, or `None` otherwise.
+/// - `exploration`: The exploration constant of UCB1; `2f64.sqrt()` is a common choice.
+/// - `seed`: The seed of the random number generator.
+///
+/// # Returns
+/// An iterator that yields the leaves reached by the rollouts.
+/// The iterator ends when the whole tree has been explored.
+pub fn mcts_reach<C, N, IN, FN, FL, FC>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    exploration: f64,
+    seed: u64,
+) -> Reachable<MonteCarloContainer<C, N, FN, FL, FC>>
+where
+    C: Ord + Copy + ToF64,
+    N: Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+{
+    let container = MonteCarloContainer::new(
+        start,
+        successor_fn,
+        leaf_check_fn,
+        cost_fn,
+        exploration,
+        seed,
+    );
+    Reachable::new(container)
+}
+
+/// Find the leaf node with the lowest cost by using Monte Carlo Tree Search
+///
+/// - `start` is the start node.
+/// - `successor_fn` returns a list of successors for a given node.
+/// - `leaf_check_fn` check if a node is leaf or not
+/// - `cost_fn` returns the final cost of a leaf node
+/// - `exploration` is the exploration constant of UCB1
+/// - `seed` is the seed of the random number generator
+/// - `max_ops` is the maximum number of search operations (rollouts) to perform
+/// - `time_limit` is the maximum duration allowed for the search operation
+///
+/// This function returns Some of a tuple of (cost, leaf node) if found, otherwise returns None
+#[allow(clippy::too_many_arguments)]
+pub fn mcts<C, N, IN, FN, FL, FC>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    exploration: f64,
+    seed: u64,
+    max_ops: usize,
+    time_limit: Duration,
+) -> Option<(C, N)>
+where
+    C: Ord + Copy + ToF64,
+    N: Clone,
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    FC: Copy + Fn(&N) -> Option<C>,
+    FL: Copy + Fn(&N) -> bool,
+{
+    let mut res = mcts_reach(
+        start,
+        successor_fn,
+        leaf_check_fn,
+        cost_fn,
+        exploration,
+        seed,
+    );
+    find_best(
+        &mut res,
+        leaf_check_fn,
+        cost_fn,
+        max_ops,
+        time_limit,
+        |_, _| {},
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{mcts, mcts_reach};
+    type Node = Vec<bool>;
+    #[test]
+    fn test_mcts() {
+        let weights = [4, 2, 6, 3, 4];
+        let profits = [100, 20, 2, 5, 10];
+        let capacity = 8;
+        let total_items = weights.len();
+
+        let successor_fn = |n: &Node| {
+            if n.len() == total_items {
+                return vec![];
+            }
+
+            let total_weight: u32 = n
+                .iter()
+                .copied()
+                .enumerate()
+                .map(|(i, b)| if b { weights[i] } else { 0 })
+                .sum();
+
+            let mut children = vec![];
+
+            let next_idx = n.len();
+            if capacity >= total_weight + weights[next_idx] {
+                let mut c1 = n.clone();
+                c1.push(true);
+                children.push(c1);
+            }
+
+            let mut c2 = n.clone();
+            c2.push(false);
+            children.push(c2);
+
+            children
+        };
+
+        let total_profit = |n: &Node| {
+            let s: u32 = n
+                .iter()
+                .copied()
+                .enumerate()
+                .map(|(i, b)| if b { profits[i] } else { 0 })
+                .sum();
+            s
+        };
+
+        let cost_fn = |n: &Node| Some(u64::from(1000 - total_profit(n)));
+        let leaf_check_fn = |n: &Node| n.len() == total_items;
+        let exploration = 2f64.sqrt();
+        let seed = 42;
+
+        // same seed, same rollouts
+        let first: Vec<Node> = mcts_reach(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            exploration,
+            seed,
+        )
+        .take(10)
+        .collect();
+        let second: Vec<Node> = mcts_reach(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            exploration,
+            seed,
+        )
+        .take(10)
+        .collect();
+        assert_eq!(first, second);
+
+        let (cost, best_node) = mcts(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            exploration,
+            seed,
+            usize::MAX,
+            Duration::from_secs(10),
+        )
+        .expect("MCTS should find a valid solution");
+        let cost = 1000 - cost;
+
+        assert_eq!(cost, 120);
+        assert_eq!(best_node, vec![true, true, false, false, false]);
+    }
+}
@@ -0,0 +1,252 @@
+//! Search Observers
+
+use std::{cell::RefCell, rc::Rc};
+
+use super::common::SearchOutcome;
+
+/// The reason why a node was discarded without being visited or expanded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PruneReason {
+    /// The lower bound of the node is not better than the cost of the best leaf found so far.
+    Bound,
+    /// The node is dominated by a node seen before.
+    Dominance,
+    /// The lower bound or the priority of the node could not be computed.
+    Infeasible,
+    /// The node was not among the best successors of its parent allowed by the branch factor.
+    BranchFactor,
+    /// The node was not among the best nodes of its depth level allowed by the beam width.
+    BeamWidth,
+}
+
+/// A trait for observing how nodes are expanded and pruned inside a node container.
+///
+/// Containers such as [`BranchAndBoundContainer`](super::BranchAndBoundContainer) and
+/// [`BeamContainer`](super::BeamContainer) notify their observer, set with `with_observer`.
+/// Every hook does nothing by default. The unit type `()` is an observer that ignores every event.
+pub trait ExpansionObserver<N> {
+    /// Called when the successors of `node` have been generated, with the number of successors.
+    fn on_expand(&mut self, _node: &N, _n_children: usize) {}
+
+    /// Called when `node` is discarded for the given reason.
+    fn on_prune(&mut self, _node: &N, _reason: PruneReason) {}
+}
+
+/// A trait for observing a traversal from start to finish.
+///
+/// [`traverse_observed`](super::traverse_observed) notifies the observer of the nodes visited, the
+/// leaves evaluated, the improvements of the best leaf and the outcome. To also observe expansions
+/// and pruning, share the observer with the container, e.g. through an `Rc<RefCell<_>>`.
+/// Every hook does nothing by default.
+pub trait SearchObserver<N, C>: ExpansionObserver<N> {
+    /// Called when the traversal visits a node, with the index of the visit.
+    fn on_visit(&mut self, _index: usize, _node: &N) {}
+
+    /// Called when a leaf is evaluated, with its cost or `None` if it is infeasible.
+    fn on_leaf(&mut self, _node: &N, _cost: Option<C>) {}
+
+    /// Called when a leaf better than every leaf found so far is found.
+    fn on_incumbent(&mut self, _node: &N, _cost: C) {}
+
+    /// Called once when the traversal stops.
+    fn on_finish(&mut self, _outcome: &SearchOutcome<C, N>) {}
+}
+
+impl<N> ExpansionObserver<N> for () {}
+
+impl<N, C> SearchObserver<N, C> for () {}
+
+impl<N, O: ExpansionObserver<N> + ?Sized> ExpansionObserver<N> for &mut O {
+    fn on_expand(&mut self, node: &N, n_children: usize) {
+        (**self).on_expand(node, n_children)
+    }
+
+    fn on_prune(&mut self, node: &N, reason: PruneReason) {
+        (**self).on_prune(node, reason)
+    }
+}
+
+impl<N, C, O: SearchObserver<N, C> + ?Sized> SearchObserver<N, C> for &mut O {
+    fn on_visit(&mut self, index: usize, node: &N) {
+        (**self).on_visit(index, node)
+    }
+
+    fn on_leaf(&mut self, node: &N, cost: Option<C>) {
+        (**self).on_leaf(node, cost)
+    }
+
+    fn on_incumbent(&mut self, node: &N, cost: C) {
+        (**self).on_incumbent(node, cost)
+    }
+
+    fn on_finish(&mut self, outcome: &SearchOutcome<C, N>) {
+        (**self).on_finish(outcome)
+    }
+}
+
+impl<N, O: ExpansionObserver<N> + ?Sized> ExpansionObserver<N> for Rc<RefCell<O>> {
+    fn on_expand(&mut self, node: &N, n_children: usize) {
+        self.borrow_mut().on_expand(node, n_children)
+    }
+
+    fn on_prune(&mut self, node: &N, reason: PruneReason) {
+        self.borrow_mut().on_prune(node, reason)
+    }
+}
+
+impl<N, C, O: SearchObserver<N, C> + ?Sized> SearchObserver<N, C> for Rc<RefCell<O>> {
+    fn on_visit(&mut self, index: usize, node: &N) {
+        self.borrow_mut().on_visit(index, node)
+    }
+
+    fn on_leaf(&mut self, node: &N, cost: Option<C>) {
+        self.borrow_mut().on_leaf(node, cost)
+    }
+
+    fn on_incumbent(&mut self, node: &N, cost: C) {
+        self.borrow_mut().on_incumbent(node, cost)
+    }
+
+    fn on_finish(&mut self, outcome: &SearchOutcome<C, N>) {
+        self.borrow_mut().on_finish(outcome)
+    }
+}
+
+/// An observer forwarding the visited nodes to a callback, as taken by [`traverse`](super::traverse).
+pub(crate) struct VisitCallback<CB>(pub(crate) CB);
+
+impl<N, CB> ExpansionObserver<N> for VisitCallback<CB> {}
+
+impl<N, C, CB: FnMut(usize, &N)> SearchObserver<N, C> for VisitCallback<CB> {
+    fn on_visit(&mut self, index: usize, node: &N) {
+        (self.0)(index, node)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+    use super::{ExpansionObserver, PruneReason, SearchObserver};
+    use crate::traversal::functional::{
+        BeamContainer, BranchAndBoundContainer, MaxOps, Reachable, SearchOutcome, traverse_observed,
+    };
+
+    type Node = Vec<bool>;
+
+    #[derive(Default)]
+    struct Counter {
+        n_visited: usize,
+        n_expanded: usize,
+        n_children: usize,
+        n_leaves: usize,
+        n_incumbents: usize,
+        n_finished: usize,
+        pruned: HashMap<PruneReason, usize>,
+    }
+
+    impl<N> ExpansionObserver<N> for Counter {
+        fn on_expand(&mut self, _node: &N, n_children: usize) {
+            self.n_expanded += 1;
+            self.n_children += n_children;
+        }
+
+        fn on_prune(&mut self, _node: &N, reason: PruneReason) {
+            *self.pruned.entry(reason).or_default() += 1;
+        }
+    }
+
+    impl<N, C> SearchObserver<N, C> for Counter {
+        fn on_visit(&mut self, _index: usize, _node: &N) {
+            self.n_visited += 1;
+        }
+
+        fn on_leaf(&mut self, _node: &N, _cost: Option<C>) {
+            self.n_leaves += 1;
+        }
+
+        fn on_incumbent(&mut self, _node: &N, _cost: C) {
+            self.n_incumbents += 1;
+        }
+
+        fn on_finish(&mut self, _outcome: &SearchOutcome<C, N>) {
+            self.n_finished += 1;
+        }
+    }
+
+    #[test]
+    fn test_observer() {
+        let weights = [2, 3, 5, 1, 4, 2];
+        let profits = [10, 12, 20, 3, 15, 7];
+        let capacity = 9;
+        let total_items = weights.len();
+
+        let total = |n: &Node, values: &[u32]| -> u32 {
+            n.iter()
+                .copied()
+                .enumerate()
+                .map(|(i, b)| if b { values[i] } else { 0 })
+                .sum()
+        };
+
+        let successor_fn = |n: &Node| {
+            if n.len() == total_items {
+                return vec![];
+            }
+
+            let mut c1 = n.clone();
+            c1.push(true);
+            let mut c2 = n.clone();
+            c2.push(false);
+            vec![c1, c2]
+        };
+        let feasible = |n: &Node| total(n, &weights) <= capacity;
+
+        let lower_bound_fn = |n: &Node| {
+            let max_remained_profit: u32 = profits[n.len()..].iter().sum();
+            feasible(n).then(|| u32::MAX - (total(n, &profits) + max_remained_profit))
+        };
+        let cost_fn = |n: &Node| feasible(n).then(|| u32::MAX - total(n, &profits));
+        let leaf_check_fn = |n: &Node| n.len() == total_items;
+
+        let counter = Rc::new(RefCell::new(Counter::default()));
+        let container = BranchAndBoundContainer::new(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+        )
+        .with_observer(counter.clone());
+        let outcome = traverse_observed(
+            &mut Reachable::new(container),
+            leaf_check_fn,
+            cost_fn,
+            1,
+            MaxOps(usize::MAX),
+            counter.clone(),
+        );
+        let (cost, _) = outcome.best().expect("BBS should find a valid solution");
+        assert_eq!(u32::MAX - cost, 37);
+
+        let counter = counter.borrow();
+        assert_eq!(counter.n_visited, outcome.n_visited);
+        assert_eq!(counter.n_leaves, outcome.n_leaves);
+        assert_eq!(counter.n_incumbents, outcome.improvements.len());
+        assert_eq!(counter.n_finished, 1);
+        assert_eq!(counter.n_children, 2 * counter.n_expanded);
+        assert!(counter.pruned[&PruneReason::Bound] > 0);
+        assert!(counter.pruned[&PruneReason::Infeasible] > 0);
+
+        let priority_fn = |n: &Node| feasible(n).then(|| total(n, &profits));
+        for (branch_factor, reason) in [(1, PruneReason::BranchFactor), (2, PruneReason::BeamWidth)]
+        {
+            let mut counter = Counter::default();
+            let container = BeamContainer::new(vec![], successor_fn, priority_fn, branch_factor, 1)
+                .with_observer(&mut counter);
+            assert!(Reachable::new(container).count() > 0);
+            assert!(counter.pruned[&reason] > 0);
+        }
+    }
+}
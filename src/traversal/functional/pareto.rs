@@ -0,0 +1,427 @@
+//! Multi-objective Search
+
+use std::{iter::FusedIterator, time::Duration};
+
+use super::common::{NodeContainer, Reachable};
+
+/// A trait representing a vector of objectives, all of them to be minimized.
+///
+/// It is implemented for tuples of up to four objectives, arrays and `Vec`. The `Ord` implementation
+/// of these types is lexicographic, and is only used to sort the Pareto front.
+pub trait Objectives: Ord + Clone {
+    /// Returns `true` if every objective of `self` is lower than or equal to the same objective of
+    /// `other`.
+    fn weakly_dominates(&self, other: &Self) -> bool;
+
+    /// Returns `true` if `self` weakly dominates `other` and is better on at least one objective.
+    fn dominates(&self, other: &Self) -> bool {
+        self.weakly_dominates(other) && self != other
+    }
+}
+
+macro_rules! impl_objectives_for_tuple {
+    ($($t:ident $i:tt),+) => {
+        impl<$($t: Ord + Clone),+> Objectives for ($($t,)+) {
+            fn weakly_dominates(&self, other: &Self) -> bool {
+                $(self.$i <= other.$i)&&+
+            }
+        }
+    };
+}
+
+impl_objectives_for_tuple!(A 0, B 1);
+impl_objectives_for_tuple!(A 0, B 1, C 2);
+impl_objectives_for_tuple!(A 0, B 1, C 2, D 3);
+
+impl<C: Ord + Clone, const K: usize> Objectives for [C; K] {
+    fn weakly_dominates(&self, other: &Self) -> bool {
+        self.iter().zip(other).all(|(a, b)| a <= b)
+    }
+}
+
+impl<C: Ord + Clone> Objectives for Vec<C> {
+    fn weakly_dominates(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a <= b)
+    }
+}
+
+/// An archive of items whose objective vectors are not dominated by each other, i.e. a Pareto
+/// front.
+///
+/// A new item is rejected if the objectives of an archived item weakly dominate its objectives,
+/// which includes an archived item with the same objectives. Otherwise it is archived, and the
+/// archived items it dominates are removed.
+#[derive(Debug, Clone)]
+pub struct ParetoArchive<C, T> {
+    entries: Vec<(C, T)>,
+}
+
+impl<C, T> Default for ParetoArchive<C, T> {
+    fn default() -> Self {
+        Self { entries: vec![] }
+    }
+}
+
+impl<C: Objectives, T> ParetoArchive<C, T> {
+    /// Creates a new empty `ParetoArchive`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Offers an item with the given objectives, and returns `true` if it has been archived.
+    pub fn insert(&mut self, objectives: C, item: T) -> bool {
+        if self.is_dominated(&objectives) {
+            return false;
+        }
+        self.entries
+            .retain(|(c, _)| !objectives.weakly_dominates(c));
+        self.entries.push((objectives, item));
+        true
+    }
+
+    /// Returns `true` if the objectives of an archived item weakly dominate `objectives`.
+    pub fn is_dominated(&self, objectives: &C) -> bool {
+        self.entries
+            .iter()
+            .any(|(c, _)| c.weakly_dominates(objectives))
+    }
+
+    /// Returns the number of archived items.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no item has been archived.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over the archived items and their objectives, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &(C, T)> {
+        self.entries.iter()
+    }
+
+    /// Consumes the archive and returns the archived items and their objectives, sorted
+    /// lexicographically by objectives.
+    pub fn into_sorted_vec(self) -> Vec<(C, T)> {
+        let mut entries = self.entries;
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+        entries
+    }
+}
+
+/// Performs a generic traversal over a tree iterator, collecting the Pareto front of the leaf nodes.
+///
+/// This function works like [`traverse`](super::traverse), but `cost_fn` returns a vector of
+/// objectives, and instead of the `queue_size` lowest costs it keeps every leaf whose objectives
+/// are not dominated by those of another leaf, see [`ParetoArchive`].
+///
+/// # Parameters
+/// - `tree`: A mutable reference to a fused iterator over the tree nodes.
+/// - `leaf_check_fn`: A function that checks if a node is a leaf.
+/// - `cost_fn`: A function that computes the objectives of a node, returning `None` if they cannot be determined.
+/// - `max_ops`: The maximum number of nodes to process.
+/// - `time_limit`: The maximum time allowed for the traversal.
+/// - `callback_fn`: A mutable callback invoked as `callback_fn(n_step, &node)` for each visited node.
+///
+/// # Returns
+/// A vector of tuples containing the objectives and the node of the non-dominated leaves, sorted lexicographically
+/// by objectives. Among leaves with the same objectives, only the first one visited is kept.
+pub fn traverse_pareto<C, N, FC, FL, CB>(
+    tree: &mut impl FusedIterator<Item = N>,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    max_ops: usize,
+    time_limit: Duration,
+    mut callback_fn: CB,
+) -> Vec<(C, N)>
+where
+    C: Objectives,
+    FC: Fn(&N) -> Option<C>,
+    FL: Fn(&N) -> bool,
+    CB: FnMut(usize, &N),
+{
+    let mut archive = ParetoArchive::new();
+    let start = std::time::Instant::now();
+    let mut n_visited = 0;
+    while n_visited < max_ops && start.elapsed() < time_limit {
+        let Some(n) = tree.next() else {
+            break;
+        };
+        callback_fn(n_visited, &n);
+        n_visited += 1;
+
+        if !leaf_check_fn(&n) {
+            continue;
+        }
+        if let Some(cost) = cost_fn(&n) {
+            archive.insert(cost, n);
+        }
+    }
+    archive.into_sorted_vec()
+}
+
+/// A container for multi-objective Branch-and-Bound traversal.
+///
+/// The objectives of the leaves found so far are kept in a [`ParetoArchive`], and a node is pruned
+/// when the vector of lower bounds on the objectives of its leaves is weakly dominated by the
+/// archive, since none of its leaves can then enter the Pareto front.
+pub struct ParetoBranchAndBoundContainer<C, N, FN, FL, FC, FC2> {
+    to_see: Vec<N>,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+    archive: ParetoArchive<C, ()>,
+}
+
+impl<C, N, IN, FN, FL, FC, FC2> ParetoBranchAndBoundContainer<C, N, FN, FL, FC, FC2>
+where
+    C: Objectives,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+    FC2: Fn(&N) -> Option<C>,
+{
+    /// Creates a new `ParetoBranchAndBoundContainer` with the given parameters.
+    pub fn new(
+        start: N,
+        successor_fn: FN,
+        leaf_check_fn: FL,
+        cost_fn: FC,
+        lower_bound_fn: FC2,
+    ) -> Self {
+        Self {
+            to_see: vec![start],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            archive: ParetoArchive::new(),
+        }
+    }
+}
+
+impl<C, N, FN, FL, FC, FC2> ParetoBranchAndBoundContainer<C, N, FN, FL, FC, FC2> {
+    /// Returns the archive of the objectives of the non-dominated leaves found so far.
+    pub fn archive(&self) -> &ParetoArchive<C, ()> {
+        &self.archive
+    }
+
+    /// Returns `true` if there is no node left to visit, which means the archive is the Pareto front.
+    pub fn is_exhausted(&self) -> bool {
+        self.to_see.is_empty()
+    }
+}
+
+impl<C, N, IN, FN, FL, FC, FC2> NodeContainer
+    for ParetoBranchAndBoundContainer<C, N, FN, FL, FC, FC2>
+where
+    C: Objectives,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+    FC2: Fn(&N) -> Option<C>,
+{
+    type Node = N;
+
+    fn pop(&mut self) -> Option<Self::Node> {
+        self.to_see.pop()
+    }
+
+    fn expand_and_push(&mut self, node: &Self::Node) {
+        if (self.leaf_check_fn)(node) {
+            if let Some(cost) = (self.cost_fn)(node) {
+                self.archive.insert(cost, ());
+            }
+            return;
+        }
+
+        if let Some(lb) = (self.lower_bound_fn)(node)
+            && !self.archive.is_dominated(&lb)
+        {
+            self.to_see.extend((self.successor_fn)(node));
+        }
+    }
+
+    fn frontier_size(&self) -> Option<usize> {
+        Some(self.to_see.len())
+    }
+}
+
+/// Creates a multi-objective Branch-and-Bound traversal iterator starting from the given node.
+///
+/// The tree is explored depth-first, and a node is pruned when the vector of lower bounds on the
+/// objectives of its leaves is weakly dominated by the objectives of a leaf found before.
+///
+/// # Parameters
+/// - `start`: The root node from which to begin the traversal.
+/// - `successor_fn`: A function that, given a node, returns an iterator over its successor nodes.
+/// - `leaf_check_fn`: A function that determines whether a given node is a leaf (terminal) node.
+/// - `cost_fn`: A function that computes the objectives of a leaf node, returning `Some(objectives)` if they can
+///   be determined, or `None` otherwise.
+/// - `lower_bound_fn`: A function that provides a lower bound on each objective for a given node, used for
+///   pruning dominated branches.
+///
+/// # Returns
+/// An iterator that yields nodes reachable from the start node in multi-objective Branch-and-Bound order.
+pub fn pareto_bbs_reach<C, N, IN, FN, FL, FC, FC2>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+) -> Reachable<ParetoBranchAndBoundContainer<C, N, FN, FL, FC, FC2>>
+where
+    C: Objectives,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+    FC2: Fn(&N) -> Option<C>,
+{
+    let container = ParetoBranchAndBoundContainer::new(
+        start,
+        successor_fn,
+        leaf_check_fn,
+        cost_fn,
+        lower_bound_fn,
+    );
+    Reachable::new(container)
+}
+
+/// Find the Pareto front of the leaf nodes by using multi-objective Branch and Bound
+///
+/// - `start` is the start node.
+/// - `successor_fn` returns a list of successors for a given node.
+/// - `leaf_check_fn` check if a node is leaf or not
+/// - `cost_fn` returns the objectives of a leaf node
+/// - `lower_bound_fn` returns the lower bounds on the objectives of a given node to decide whether to search deeper
+///   or not
+/// - `max_ops` is the maximum number of search operations to perform
+/// - `time_limit` is the maximum duration allowed for the search operation
+///
+/// This function returns the non-dominated leaves found and their objectives, sorted lexicographically by objectives
+pub fn pareto_bbs<C, N, IN, FN, FL, FC, FC2>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+    max_ops: usize,
+    time_limit: Duration,
+) -> Vec<(C, N)>
+where
+    C: Objectives,
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    FC: Copy + Fn(&N) -> Option<C>,
+    FL: Copy + Fn(&N) -> bool,
+    FC2: Fn(&N) -> Option<C>,
+{
+    let mut res = pareto_bbs_reach(start, successor_fn, leaf_check_fn, cost_fn, lower_bound_fn);
+    traverse_pareto(
+        &mut res,
+        leaf_check_fn,
+        cost_fn,
+        max_ops,
+        time_limit,
+        |_, _| {},
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{Objectives, pareto_bbs, pareto_bbs_reach, traverse_pareto};
+    use crate::traversal::functional::dfs_reach;
+
+    /// The mode chosen for each of the jobs scheduled so far.
+    type Node = Vec<usize>;
+
+    #[test]
+    fn test_pareto() {
+        // the (cost, time) of each mode of each job
+        let modes = [
+            [(3, 9), (5, 4), (8, 2)],
+            [(2, 7), (4, 5), (9, 1)],
+            [(1, 8), (6, 3), (7, 3)],
+            [(4, 4), (2, 6), (5, 5)],
+        ];
+        let total_jobs = modes.len();
+
+        let successor_fn = |n: &Node| {
+            if n.len() == total_jobs {
+                return vec![];
+            }
+            (0..modes[n.len()].len())
+                .map(|m| {
+                    let mut c = n.clone();
+                    c.push(m);
+                    c
+                })
+                .collect()
+        };
+        let leaf_check_fn = |n: &Node| n.len() == total_jobs;
+        let cost_fn = |n: &Node| {
+            let cost = n.iter().enumerate().map(|(j, &m)| modes[j][m].0).sum();
+            let time = n.iter().enumerate().map(|(j, &m)| modes[j][m].1).sum();
+            Some((cost, time))
+        };
+        let lower_bound_fn = |n: &Node| {
+            let (cost, time): (u32, u32) = cost_fn(n).unwrap();
+            let remained = &modes[n.len()..];
+            let min_cost: u32 = remained
+                .iter()
+                .map(|ms| ms.iter().map(|m| m.0).min().unwrap())
+                .sum();
+            let min_time: u32 = remained
+                .iter()
+                .map(|ms| ms.iter().map(|m| m.1).min().unwrap())
+                .sum();
+            Some((cost + min_cost, time + min_time))
+        };
+
+        let front = pareto_bbs(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            usize::MAX,
+            Duration::from_secs(10),
+        );
+
+        // the front of the exhaustive search
+        let mut n_visited = 0;
+        let expected = traverse_pareto(
+            &mut dfs_reach(vec![], successor_fn),
+            leaf_check_fn,
+            cost_fn,
+            usize::MAX,
+            Duration::from_secs(10),
+            |i, _| n_visited = i + 1,
+        );
+        assert_eq!(n_visited, 1 + 3 + 9 + 27 + 81);
+
+        let costs: Vec<_> = front.iter().map(|(c, _)| *c).collect();
+        let expected_costs: Vec<_> = expected.iter().map(|(c, _)| *c).collect();
+        assert_eq!(costs, expected_costs);
+        assert!(costs.len() > 1);
+        assert!(costs.windows(2).all(|w| w[0] < w[1]));
+        for a in &costs {
+            assert!(costs.iter().all(|b| !b.dominates(a)));
+        }
+        for (c, n) in &front {
+            assert_eq!(cost_fn(n), Some(*c));
+        }
+
+        // dominated branches are pruned
+        let n_pruned_visited =
+            pareto_bbs_reach(vec![], successor_fn, leaf_check_fn, cost_fn, lower_bound_fn).count();
+        assert!(n_pruned_visited < n_visited);
+    }
+}
@@ -0,0 +1,503 @@
+//! Parallel Branch and Bound Search
+
+use std::{
+    num::NonZeroUsize,
+    panic,
+    sync::{
+        Mutex, MutexGuard, TryLockError,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use super::{
+    bbs::{BranchAndBoundContainer, BranchAndBoundResult, Tolerance},
+    common::NodeContainer,
+    dominance::DominanceFilter,
+    incumbent::SharedIncumbent,
+    observer::ExpansionObserver,
+};
+
+/// The state shared by the workers of a parallel Branch-and-Bound search.
+struct Shared<'a, NC> {
+    /// One container per worker. The owner pops the nodes it pushed last (depth-first), and the
+    /// other workers steal the nodes waiting the longest, where the largest subtrees are.
+    containers: Vec<Mutex<&'a mut NC>>,
+    /// The number of nodes waiting in a container or being processed. The search space is
+    /// exhausted when it drops to zero.
+    pending: AtomicUsize,
+    n_ops: AtomicUsize,
+    stop: AtomicBool,
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// A container whose nodes can be taken over by the other workers.
+trait WorkStealing: NodeContainer {
+    fn steal(&mut self) -> Option<Self::Node>;
+
+    fn push(&mut self, node: Self::Node);
+}
+
+impl<C, N, IN, FN, FL, FC, FC2, D, O, T> WorkStealing
+    for BranchAndBoundContainer<C, N, FN, FL, FC, FC2, D, O, SharedIncumbent<C>, T>
+where
+    C: Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+    FC2: Fn(&N) -> Option<C>,
+    D: DominanceFilter<N>,
+    O: ExpansionObserver<N>,
+    T: Tolerance<C>,
+{
+    fn steal(&mut self) -> Option<N> {
+        BranchAndBoundContainer::steal(self)
+    }
+
+    fn push(&mut self, node: N) {
+        BranchAndBoundContainer::push(self, node)
+    }
+}
+
+impl<NC: WorkStealing> Shared<'_, NC> {
+    fn pop(&self, worker: usize) -> Option<NC::Node> {
+        if let Some(node) = lock(&self.containers[worker]).pop() {
+            return Some(node);
+        }
+        let n_workers = self.containers.len();
+        (1..n_workers)
+            .map(|i| (worker + i) % n_workers)
+            .find_map(|victim| match self.containers[victim].try_lock() {
+                Ok(mut container) => container.steal(),
+                Err(TryLockError::Poisoned(e)) => e.into_inner().steal(),
+                // the victim is expanding a node, it will be tried again
+                Err(TryLockError::WouldBlock) => None,
+            })
+    }
+}
+
+/// Stops every worker when dropped while its worker is panicking, so that the other workers do
+/// not wait forever for the nodes the panicking one will never process.
+struct StopOnPanic<'a>(&'a AtomicBool);
+
+impl Drop for StopOnPanic<'_> {
+    fn drop(&mut self) {
+        if thread::panicking() {
+            self.0.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Waits for another worker to push nodes, first by yielding and then by sleeping for
+/// exponentially longer durations, up to about a millisecond.
+fn back_off(n_idle: &mut u32) {
+    const N_YIELDS: u32 = 16;
+    if *n_idle < N_YIELDS {
+        thread::yield_now();
+    } else {
+        thread::sleep(Duration::from_micros(1 << (*n_idle - N_YIELDS).min(10)));
+    }
+    *n_idle += 1;
+}
+
+fn work<C, N, NC, FL, FC>(
+    worker: usize,
+    shared: &Shared<NC>,
+    leaf_check_fn: &FL,
+    cost_fn: &FC,
+    max_ops: usize,
+    time_limit: Duration,
+    start: Instant,
+) -> Option<(C, N)>
+where
+    C: Ord + Copy,
+    NC: WorkStealing<Node = N>,
+    FL: Fn(&N) -> bool,
+    FC: Fn(&N) -> Option<C>,
+{
+    let _stop_on_panic = StopOnPanic(&shared.stop);
+    let mut best: Option<(C, N)> = None;
+    let mut n_idle = 0;
+
+    while !shared.stop.load(Ordering::Relaxed) {
+        let Some(node) = shared.pop(worker) else {
+            if shared.pending.load(Ordering::SeqCst) == 0 {
+                break;
+            }
+            back_off(&mut n_idle);
+            continue;
+        };
+        n_idle = 0;
+
+        if shared.n_ops.fetch_add(1, Ordering::Relaxed) >= max_ops || start.elapsed() >= time_limit
+        {
+            // keep the node so that it still counts in the lower bound
+            lock(&shared.containers[worker]).push(node);
+            shared.stop.store(true, Ordering::Relaxed);
+            break;
+        }
+
+        {
+            let mut container = lock(&shared.containers[worker]);
+            let n_waiting = container.frontier_size().unwrap_or(0);
+            container.expand_and_push(&node);
+            let n_pushed = container.frontier_size().unwrap_or(0) - n_waiting;
+            shared.pending.fetch_add(n_pushed, Ordering::SeqCst);
+        }
+
+        if leaf_check_fn(&node)
+            && let Some(cost) = cost_fn(&node)
+            && best.as_ref().is_none_or(|(c, _)| *c > cost)
+        {
+            best = Some((cost, node));
+        }
+        shared.pending.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    best
+}
+
+/// Find the leaf node with the lowest cost by using Branch and Bound on several threads, with one container per worker
+///
+/// - `containers` holds the container of each worker, sharing the same [`SharedIncumbent`]. The nodes they hold
+///   are the roots of the search, e.g. the start node in the first one and none in the others, created with
+///   [`BranchAndBoundContainer::empty`].
+/// - `leaf_check_fn` check if a node is leaf or not
+/// - `cost_fn` returns the final cost of a leaf node
+/// - `max_ops` is the maximum number of search operations to perform, counted over all threads
+/// - `time_limit` is the maximum duration allowed for the search operation
+///
+/// One thread is spawned per container. Each worker visits the nodes of its own container like
+/// [`bbs_solve`](super::bbs_solve), and idle workers steal the nodes waiting the longest in the
+/// containers of the others. The containers may be configured like for a single search, e.g. with
+/// a dominance filter, an observer, a tolerance or an incumbent seeded with an upper bound; each
+/// worker only consults its own dominance filter and notifies its own observer. The containers are
+/// left as the search stopped, so that their observers can be inspected.
+#[allow(clippy::type_complexity)]
+pub fn pbbs_solve_with<C, N, IN, FN, FL, FC, FC2, D, O, T>(
+    containers: &mut [BranchAndBoundContainer<
+        C,
+        N,
+        FN,
+        FL,
+        FC,
+        FC2,
+        D,
+        O,
+        SharedIncumbent<C>,
+        T,
+    >],
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    max_ops: usize,
+    time_limit: Duration,
+) -> BranchAndBoundResult<C, N>
+where
+    C: Ord + Copy + Send,
+    N: Send,
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN + Send,
+    FL: Fn(&N) -> bool + Send + Sync,
+    FC: Fn(&N) -> Option<C> + Send + Sync,
+    FC2: Fn(&N) -> Option<C> + Send,
+    D: DominanceFilter<N> + Send,
+    O: ExpansionObserver<N> + Send,
+    T: Tolerance<C> + Send,
+{
+    let start_time = Instant::now();
+    let pending = containers
+        .iter()
+        .map(|c| c.frontier_size().unwrap_or(0))
+        .sum();
+    let shared = Shared {
+        containers: containers.iter_mut().map(Mutex::new).collect(),
+        pending: AtomicUsize::new(pending),
+        n_ops: AtomicUsize::new(0),
+        stop: AtomicBool::new(false),
+    };
+
+    let best = thread::scope(|scope| {
+        let handles: Vec<_> = (0..shared.containers.len())
+            .map(|worker| {
+                let shared = &shared;
+                let (leaf_check_fn, cost_fn) = (&leaf_check_fn, &cost_fn);
+                scope.spawn(move || {
+                    work(
+                        worker,
+                        shared,
+                        leaf_check_fn,
+                        cost_fn,
+                        max_ops,
+                        time_limit,
+                        start_time,
+                    )
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .filter_map(|h| h.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+            .min_by_key(|(cost, _)| *cost)
+    });
+    drop(shared);
+
+    BranchAndBoundResult {
+        best,
+        lower_bound: containers.iter().filter_map(|c| c.lower_bound()).min(),
+        exhausted: containers.iter().all(|c| c.is_exhausted()),
+    }
+}
+
+/// Find the leaf node with the lowest cost by using Branch and Bound on several threads, reporting the proof status
+///
+/// - `start` is the start node.
+/// - `successor_fn` returns a list of successors for a given node.
+/// - `leaf_check_fn` check if a node is leaf or not
+/// - `cost_fn` returns the final cost of a leaf node
+/// - `lower_bound_fn` returns the lower bound of a given node to decide whether to search deeper or not
+/// - `n_threads` is the number of worker threads, or `0` to use the available parallelism
+/// - `max_ops` is the maximum number of search operations to perform, counted over all threads
+/// - `time_limit` is the maximum duration allowed for the search operation
+///
+/// Each worker explores its own part of the tree depth-first like [`bbs`](super::bbs), and idle
+/// workers steal the shallowest waiting nodes of the others. The cost of the best leaf is shared
+/// through a [`SharedIncumbent`], so a leaf found by one worker prunes the branches of all of them.
+/// The order in which nodes are visited, and therefore which of several optimal leaves is returned,
+/// depends on the scheduling of the threads. If one of the functions panics on a worker thread,
+/// all workers stop and the panic is resumed on the calling thread. See [`pbbs_solve_with`] to
+/// configure the container of each worker.
+#[allow(clippy::too_many_arguments)]
+pub fn pbbs_solve<C, N, IN, FN, FL, FC, FC2>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+    n_threads: usize,
+    max_ops: usize,
+    time_limit: Duration,
+) -> BranchAndBoundResult<C, N>
+where
+    C: Ord + Copy + Send,
+    N: Send,
+    IN: IntoIterator<Item = N>,
+    FN: Fn(&N) -> IN + Sync,
+    FL: Fn(&N) -> bool + Sync,
+    FC: Fn(&N) -> Option<C> + Sync,
+    FC2: Fn(&N) -> Option<C> + Sync,
+{
+    let n_threads = match n_threads {
+        0 => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+        n => n,
+    };
+    let incumbent = SharedIncumbent::new();
+    let mut start = Some(start);
+    let mut containers: Vec<_> = (0..n_threads)
+        .map(|_| {
+            let fns = (&successor_fn, &leaf_check_fn, &cost_fn, &lower_bound_fn);
+            match start.take() {
+                Some(start) => BranchAndBoundContainer::new(start, fns.0, fns.1, fns.2, fns.3),
+                None => BranchAndBoundContainer::empty(fns.0, fns.1, fns.2, fns.3),
+            }
+            .with_incumbent(incumbent.clone())
+        })
+        .collect();
+    pbbs_solve_with(
+        &mut containers,
+        &leaf_check_fn,
+        &cost_fn,
+        max_ops,
+        time_limit,
+    )
+}
+
+/// Find the leaf node with the lowest cost by using Branch and Bound on several threads
+///
+/// - `start` is the start node.
+/// - `successor_fn` returns a list of successors for a given node.
+/// - `leaf_check_fn` check if a node is leaf or not
+/// - `cost_fn` returns the final cost of a leaf node
+/// - `lower_bound_fn` returns the lower bound of a given node to decide whether to search deeper or not
+/// - `n_threads` is the number of worker threads, or `0` to use the available parallelism
+/// - `max_ops` is the maximum number of search operations to perform, counted over all threads
+/// - `time_limit` is the maximum duration allowed for the search operation
+///
+/// This function returns Some of a tuple of (cost, leaf node) if found, otherwise returns None.
+/// See [`pbbs_solve`] for how the work is split between the threads.
+#[allow(clippy::too_many_arguments)]
+pub fn pbbs<C, N, IN, FN, FL, FC, FC2>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FL,
+    cost_fn: FC,
+    lower_bound_fn: FC2,
+    n_threads: usize,
+    max_ops: usize,
+    time_limit: Duration,
+) -> Option<(C, N)>
+where
+    C: Ord + Copy + Send,
+    N: Send,
+    IN: IntoIterator<Item = N>,
+    FN: Fn(&N) -> IN + Sync,
+    FL: Fn(&N) -> bool + Sync,
+    FC: Fn(&N) -> Option<C> + Sync,
+    FC2: Fn(&N) -> Option<C> + Sync,
+{
+    pbbs_solve(
+        start,
+        successor_fn,
+        leaf_check_fn,
+        cost_fn,
+        lower_bound_fn,
+        n_threads,
+        max_ops,
+        time_limit,
+    )
+    .best
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+
+    use super::{pbbs_solve, pbbs_solve_with};
+    use crate::traversal::functional::{BranchAndBoundContainer, SharedIncumbent, bbs};
+
+    type Node = Vec<bool>;
+
+    #[test]
+    fn test_pbbs() {
+        let weights = [2, 3, 5, 1, 4, 2, 6, 3, 4, 2, 5, 1];
+        let profits = [10, 12, 20, 3, 15, 7, 22, 9, 14, 6, 18, 2];
+        let capacity = 20;
+        let total_items = weights.len();
+
+        let total = |n: &Node, values: &[u32]| -> u32 {
+            n.iter()
+                .copied()
+                .enumerate()
+                .map(|(i, b)| if b { values[i] } else { 0 })
+                .sum()
+        };
+
+        let successor_fn = |n: &Node| {
+            if n.len() == total_items {
+                return vec![];
+            }
+
+            let mut children = vec![];
+
+            let next_idx = n.len();
+            if capacity >= total(n, &weights) + weights[next_idx] {
+                let mut c1 = n.clone();
+                c1.push(true);
+                children.push(c1);
+            }
+
+            let mut c2 = n.clone();
+            c2.push(false);
+            children.push(c2);
+
+            children
+        };
+
+        let lower_bound_fn = |n: &Node| {
+            let max_remained_profit: u32 = profits[n.len()..].iter().sum();
+            Some(u32::MAX - (total(n, &profits) + max_remained_profit))
+        };
+        let cost_fn = |n: &Node| Some(u32::MAX - total(n, &profits));
+        let leaf_check_fn = |n: &Node| n.len() == total_items;
+
+        let (expected_cost, _) = bbs(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+            usize::MAX,
+            Duration::from_secs(10),
+        )
+        .expect("BBS should find a valid solution");
+
+        for n_threads in [1, 4] {
+            let result = pbbs_solve(
+                vec![],
+                successor_fn,
+                leaf_check_fn,
+                cost_fn,
+                lower_bound_fn,
+                n_threads,
+                usize::MAX,
+                Duration::from_secs(10),
+            );
+            assert!(result.exhausted);
+            assert!(result.is_optimal());
+            let (cost, node) = result.best.expect("PBBS should find a valid solution");
+            assert_eq!(cost, expected_cost);
+            assert!(total(&node, &weights) <= capacity);
+        }
+
+        // every visited node is checked for being a leaf by its container and by its worker
+        let n_checks = AtomicUsize::new(0);
+        let counting_leaf_check_fn = |n: &Node| {
+            n_checks.fetch_add(1, Ordering::Relaxed);
+            leaf_check_fn(n)
+        };
+        let (n_threads, max_ops) = (4, 5);
+        let incumbent = SharedIncumbent::new();
+        let mut containers: Vec<_> = (0..n_threads)
+            .map(|i| {
+                let fns = (
+                    successor_fn,
+                    &counting_leaf_check_fn,
+                    cost_fn,
+                    lower_bound_fn,
+                );
+                match i {
+                    0 => BranchAndBoundContainer::new(vec![], fns.0, fns.1, fns.2, fns.3),
+                    _ => BranchAndBoundContainer::empty(fns.0, fns.1, fns.2, fns.3),
+                }
+                .with_incumbent(incumbent.clone())
+            })
+            .collect();
+        let result = pbbs_solve_with(
+            &mut containers,
+            &counting_leaf_check_fn,
+            cost_fn,
+            max_ops,
+            Duration::from_secs(10),
+        );
+        assert!(n_checks.into_inner() <= 2 * (max_ops + n_threads));
+        assert!(!result.exhausted);
+        assert!(!result.is_optimal());
+        assert!(result.lower_bound.is_some());
+
+        // a panicking worker stops the others instead of leaving them waiting for its nodes
+        let panicking_successor_fn = |n: &Node| {
+            assert!(n != &[false; 6], "successor_fn failed");
+            successor_fn(n)
+        };
+        let result = std::panic::catch_unwind(|| {
+            pbbs_solve(
+                vec![],
+                panicking_successor_fn,
+                leaf_check_fn,
+                cost_fn,
+                lower_bound_fn,
+                4,
+                usize::MAX,
+                Duration::from_secs(10),
+            )
+        });
+        assert!(result.is_err());
+    }
+}
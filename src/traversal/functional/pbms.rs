@@ -0,0 +1,281 @@
+//! Parallel Beam Search
+
+use std::{cmp::Reverse, collections::VecDeque, num::NonZeroUsize, panic, thread, time::Duration};
+
+use super::common::{NodeContainer, Reachable, find_best};
+
+/// A container for Beam Search traversal expanding and scoring each depth level on several threads.
+///
+/// The nodes of a level are kept as they are visited. When the level is exhausted, they are split
+/// into contiguous chunks, one per thread, whose successors are generated and scored concurrently.
+/// The first chunk is expanded on the calling thread, so no thread is spawned with `n_threads` set
+/// to 1 or for a level of a single node.
+/// The best `beam_width` successors then form the next level. Among successors with the same
+/// priority, those generated first are kept first, so the nodes visited are exactly those of
+/// [`BeamContainer`](super::BeamContainer) with the same parameters, in the same order.
+pub struct ParallelBeamContainer<N, FN, FP> {
+    to_see: VecDeque<N>,
+    parents: Vec<N>,
+    successor_fn: FN,
+    priority_fn: FP,
+    branch_factor: usize,
+    beam_width: usize,
+    n_threads: usize,
+    truncated: bool,
+}
+
+impl<N, IN, FN, FP, P> ParallelBeamContainer<N, FN, FP>
+where
+    N: Clone + Send,
+    FN: Fn(&N) -> IN + Sync,
+    IN: IntoIterator<Item = N>,
+    FP: Fn(&N) -> Option<P> + Sync,
+    P: Ord + Copy + Send,
+{
+    /// Creates a new `ParallelBeamContainer` with the given parameters.
+    ///
+    /// `n_threads` is the number of threads expanding a level, or `0` to use the available parallelism.
+    pub fn new(
+        start: N,
+        successor_fn: FN,
+        priority_fn: FP,
+        branch_factor: usize,
+        beam_width: usize,
+        n_threads: usize,
+    ) -> Self {
+        let n_threads = match n_threads {
+            0 => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+            n => n,
+        };
+        Self {
+            to_see: vec![start].into(),
+            parents: vec![],
+            successor_fn,
+            priority_fn,
+            branch_factor,
+            beam_width,
+            n_threads,
+            truncated: false,
+        }
+    }
+
+    /// Returns `true` if any depth level had more candidates than `beam_width`, i.e. some nodes
+    /// were discarded because of the beam width.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Expands the nodes of the level just visited and selects the next level.
+    fn next_level(&mut self) {
+        let mut rest = std::mem::take(&mut self.parents);
+        let chunk_size = rest.len().div_ceil(self.n_threads);
+        let mut chunks = vec![];
+        while rest.len() > chunk_size {
+            let tail = rest.split_off(chunk_size);
+            chunks.push(rest);
+            rest = tail;
+        }
+        chunks.push(rest);
+
+        let (successor_fn, priority_fn) = (&self.successor_fn, &self.priority_fn);
+        let branch_factor = self.branch_factor;
+        let expand = move |chunk: Vec<N>| {
+            let mut candidates = vec![];
+            for parent in chunk {
+                let mut successors: Vec<_> = successor_fn(&parent)
+                    .into_iter()
+                    .filter_map(|n| Some((priority_fn(&n)?, n)))
+                    .collect();
+                successors.sort_by_key(|s| Reverse(s.0));
+                successors.truncate(branch_factor);
+                candidates.extend(successors);
+            }
+            candidates
+        };
+
+        // the first chunk is expanded on the current thread, so a single chunk spawns no thread
+        let mut chunks = chunks.into_iter();
+        let first = chunks.next().unwrap_or_default();
+        let mut candidates: Vec<(P, N)> = thread::scope(|scope| {
+            let handles: Vec<_> = chunks
+                .map(|chunk| scope.spawn(move || expand(chunk)))
+                .collect();
+            let mut candidates = expand(first);
+            for h in handles {
+                candidates.extend(h.join().unwrap_or_else(|e| panic::resume_unwind(e)));
+            }
+            candidates
+        });
+
+        // the sort is stable, so ties keep the order in which the successors were generated
+        candidates.sort_by_key(|s| Reverse(s.0));
+        if candidates.len() > self.beam_width {
+            self.truncated = true;
+        }
+        self.to_see
+            .extend(candidates.into_iter().take(self.beam_width).map(|(_, n)| n));
+    }
+}
+
+impl<N, IN, FN, FP, P> NodeContainer for ParallelBeamContainer<N, FN, FP>
+where
+    N: Clone + Send,
+    FN: Fn(&N) -> IN + Sync,
+    IN: IntoIterator<Item = N>,
+    FP: Fn(&N) -> Option<P> + Sync,
+    P: Ord + Copy + Send,
+{
+    type Node = N;
+
+    fn pop(&mut self) -> Option<Self::Node> {
+        if self.to_see.is_empty() && !self.parents.is_empty() {
+            self.next_level();
+        }
+        self.to_see.pop_front()
+    }
+
+    fn expand_and_push(&mut self, node: &Self::Node) {
+        // the expansion is deferred until the whole level has been visited
+        self.parents.push(node.clone());
+    }
+
+    fn frontier_size(&self) -> Option<usize> {
+        Some(self.to_see.len() + self.parents.len())
+    }
+}
+
+/// Creates a Beam Search traversal iterator expanding and scoring each depth level on several threads.
+///
+/// This function works like [`bms_reach`](super::bms_reach) and visits the same nodes in the same
+/// order, but the successors of all the nodes of a level are generated and scored concurrently
+/// once the level has been visited, which pays off when `successor_fn` or `priority_fn` is
+/// expensive. Nodes are cloned when visited to be expanded later.
+///
+/// # Parameters
+/// - `start`: The root node from which to begin the traversal.
+/// - `successor_fn`: A function that, given a node, returns an iterator over its successor nodes.
+/// - `priority_fn`: A function that evaluates a node for selection, returning `Some(priority)` where higher
+///   priorities are better, or `None` if the node cannot be evaluated.
+/// - `branch_factor`: The maximum number of successors to consider from each node.
+/// - `beam_width`: The maximum number of nodes to keep at each depth level.
+/// - `n_threads`: The number of threads expanding a level, or `0` to use the available parallelism.
+///
+/// # Returns
+/// An iterator that yields nodes reachable from the start node in beam search order.
+pub fn pbms_reach<N, IN, FN, FP, P>(
+    start: N,
+    successor_fn: FN,
+    priority_fn: FP,
+    branch_factor: usize,
+    beam_width: usize,
+    n_threads: usize,
+) -> Reachable<ParallelBeamContainer<N, FN, FP>>
+where
+    N: Clone + Send,
+    FN: Fn(&N) -> IN + Sync,
+    IN: IntoIterator<Item = N>,
+    FP: Fn(&N) -> Option<P> + Sync,
+    P: Ord + Copy + Send,
+{
+    let container = ParallelBeamContainer::new(
+        start,
+        successor_fn,
+        priority_fn,
+        branch_factor,
+        beam_width,
+        n_threads,
+    );
+    Reachable::new(container)
+}
+
+/// Find the leaf node with the lowest cost by using Beam Search expanding each level on several threads
+///
+/// - `start` is the start node.
+/// - `successor_fn` returns a list of successors for a given node.
+/// - `leaf_check_fn` checks if a node is a leaf or not
+/// - `cost_fn` returns the final cost of a leaf node
+/// - `priority_fn` returns the priority of a given node to sort and select k-best
+/// - `branch_factor` decides maximum number of branches from a node
+/// - `beam_width` decides maximum number of nodes at each depth.
+/// - `n_threads` is the number of threads expanding a level, or `0` to use the available parallelism
+/// - `max_ops` sets the maximum number of search operations to perform.
+/// - `time_limit` sets the time limit for the search.
+///
+/// This function returns Some of a tuple of (cost, leaf node) if found, otherwise returns None.
+/// The result is the same as the one of [`bms`](super::bms) with the same parameters.
+#[allow(clippy::too_many_arguments)]
+pub fn pbms<N, IN, FN, FC, FP, C, P, FR>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FR,
+    cost_fn: FC,
+    priority_fn: FP,
+    branch_factor: usize,
+    beam_width: usize,
+    n_threads: usize,
+    max_ops: usize,
+    time_limit: Duration,
+) -> Option<(C, N)>
+where
+    N: Clone + Send,
+    IN: IntoIterator<Item = N>,
+    FN: Fn(&N) -> IN + Sync,
+    FC: Fn(&N) -> Option<C>,
+    FP: Fn(&N) -> Option<P> + Sync,
+    C: Ord + Copy,
+    P: Ord + Copy + Send,
+    FR: Fn(&N) -> bool,
+{
+    let mut res = pbms_reach(
+        start,
+        successor_fn,
+        priority_fn,
+        branch_factor,
+        beam_width,
+        n_threads,
+    );
+    find_best(
+        &mut res,
+        leaf_check_fn,
+        cost_fn,
+        max_ops,
+        time_limit,
+        |_, _| {},
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::pbms_reach;
+    use crate::traversal::functional::bms_reach;
+
+    type Node = Vec<u32>;
+
+    #[test]
+    fn test_pbms() {
+        // priorities with many ties, so that the selection depends on the tie-breaking
+        let successor_fn = |n: &Node| {
+            if n.len() == 5 {
+                return vec![];
+            }
+            (0..4)
+                .map(|i| {
+                    let mut c = n.clone();
+                    c.push(i);
+                    c
+                })
+                .collect()
+        };
+        let priority_fn = |n: &Node| {
+            let sum: u32 = n.iter().sum();
+            (sum % 5 != 4).then_some(sum % 3)
+        };
+
+        let sequential: Vec<_> = bms_reach(vec![], successor_fn, priority_fn, 3, 7).collect();
+        for n_threads in [1, 2, 3, 8] {
+            let parallel: Vec<_> =
+                pbms_reach(vec![], successor_fn, priority_fn, 3, 7, n_threads).collect();
+            assert_eq!(parallel, sequential);
+        }
+    }
+}
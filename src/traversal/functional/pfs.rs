@@ -5,8 +5,9 @@ use std::{collections::BinaryHeap, time::Duration};
 use crate::utils::ScoredItem;
 
 use super::{
-    common::{NodeContainer, Reachable},
+    common::{NodeContainer, Reachable, SearchOutcome, find_best_until},
     find_best,
+    stop::StopCondition,
 };
 
 /// A container for Priority-First traversal.
@@ -57,6 +58,10 @@ where
             }
         }
     }
+
+    fn frontier_size(&self) -> Option<usize> {
+        Some(self.to_see.len())
+    }
 }
 
 /// Creates a Priority-First Search traversal iterator starting from the given node.
@@ -128,6 +133,38 @@ where
     )
 }
 
+/// Find the leaf node with the lowest cost until the given stop condition is met by using Priority First Search
+///
+/// - `start` is the start node.
+/// - `successor_fn` returns a list of successors for a given node.
+/// - `leaf_check_fn` check if a node is leaf or not
+/// - `cost_fn` returns the final cost of a leaf node
+/// - `priority_fn` returns the priority of a node, higher is better
+/// - `stop_condition` decides when to stop the search, e.g. `Any(MaxOps(1000), TargetCost(0))`
+///
+/// This function returns a [`SearchOutcome`] holding the best leaf found, if any, and the reason why the search stopped
+pub fn pfs_until<N, IN, FN, FC, FP, C, P, FR, S>(
+    start: N,
+    successor_fn: FN,
+    leaf_check_fn: FR,
+    cost_fn: FC,
+    priority_fn: FP,
+    stop_condition: S,
+) -> SearchOutcome<C, N>
+where
+    IN: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    FC: Fn(&N) -> Option<C>,
+    FP: Fn(&N) -> Option<P>,
+    C: Ord + Copy + Default,
+    P: Ord + Copy,
+    FR: Fn(&N) -> bool,
+    S: StopCondition<C>,
+{
+    let mut res = pfs_reach(start, successor_fn, priority_fn);
+    find_best_until(&mut res, leaf_check_fn, cost_fn, stop_condition, |_, _| {})
+}
+
 #[cfg(test)]
 mod test {
     use std::time::Duration;
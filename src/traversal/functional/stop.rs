@@ -0,0 +1,323 @@
+//! Stop Conditions
+
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use super::common::{Improvement, TerminationReason};
+
+/// A snapshot of the progress of a traversal, passed to [`StopCondition::should_stop`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchProgress<C> {
+    /// The number of nodes visited so far.
+    pub n_ops: usize,
+    /// The number of leaves with a cost found so far.
+    pub n_solutions: usize,
+    /// The time elapsed since the start of the traversal.
+    pub elapsed: Duration,
+    /// The cost of the best leaf found so far.
+    pub best_cost: Option<C>,
+    /// The last improvement of the best leaf.
+    pub last_improvement: Option<Improvement<C>>,
+    /// The number of nodes waiting to be visited, if the traversal reports it.
+    pub frontier_size: Option<usize>,
+}
+
+impl<C> SearchProgress<C> {
+    /// Returns the number of nodes visited since the best leaf was found, or since the start.
+    pub fn ops_since_improvement(&self) -> usize {
+        self.last_improvement
+            .as_ref()
+            .map_or(self.n_ops, |imp| self.n_ops - imp.op_index - 1)
+    }
+
+    /// Returns the time elapsed since the best leaf was found, or since the start.
+    pub fn time_since_improvement(&self) -> Duration {
+        self.last_improvement
+            .as_ref()
+            .map_or(self.elapsed, |imp| self.elapsed.saturating_sub(imp.elapsed))
+    }
+}
+
+/// A trait representing a condition to stop a traversal.
+///
+/// The condition is checked before each node is pulled from the traversal. Conditions can be
+/// combined with [`Any`] and [`All`], e.g. `Any(MaxOps(1000), TargetCost(0))`, and any closure
+/// taking a [`SearchProgress`] and returning an `Option<TerminationReason>` is a condition too.
+pub trait StopCondition<C> {
+    /// Returns the reason to stop the traversal, or `None` to continue.
+    fn should_stop(&mut self, progress: &SearchProgress<C>) -> Option<TerminationReason>;
+}
+
+impl<C, F> StopCondition<C> for F
+where
+    F: FnMut(&SearchProgress<C>) -> Option<TerminationReason>,
+{
+    fn should_stop(&mut self, progress: &SearchProgress<C>) -> Option<TerminationReason> {
+        self(progress)
+    }
+}
+
+/// Stops when either of the two conditions is met, reporting the reason of the first one met.
+#[derive(Debug, Clone)]
+pub struct Any<A, B>(pub A, pub B);
+
+impl<C, A: StopCondition<C>, B: StopCondition<C>> StopCondition<C> for Any<A, B> {
+    fn should_stop(&mut self, progress: &SearchProgress<C>) -> Option<TerminationReason> {
+        self.0
+            .should_stop(progress)
+            .or_else(|| self.1.should_stop(progress))
+    }
+}
+
+/// Stops when both conditions are met, reporting the reason of the second one.
+#[derive(Debug, Clone)]
+pub struct All<A, B>(pub A, pub B);
+
+impl<C, A: StopCondition<C>, B: StopCondition<C>> StopCondition<C> for All<A, B> {
+    fn should_stop(&mut self, progress: &SearchProgress<C>) -> Option<TerminationReason> {
+        let first = self.0.should_stop(progress);
+        let second = self.1.should_stop(progress);
+        first.and(second)
+    }
+}
+
+/// Stops when the given number of nodes have been visited.
+#[derive(Debug, Clone, Copy)]
+pub struct MaxOps(pub usize);
+
+impl<C> StopCondition<C> for MaxOps {
+    fn should_stop(&mut self, progress: &SearchProgress<C>) -> Option<TerminationReason> {
+        (progress.n_ops >= self.0).then_some(TerminationReason::MaxOps)
+    }
+}
+
+/// Stops when the given duration has elapsed.
+#[derive(Debug, Clone, Copy)]
+pub struct TimeLimit(pub Duration);
+
+impl<C> StopCondition<C> for TimeLimit {
+    fn should_stop(&mut self, progress: &SearchProgress<C>) -> Option<TerminationReason> {
+        (progress.elapsed >= self.0).then_some(TerminationReason::TimeLimit)
+    }
+}
+
+/// Stops when a leaf with a cost lower than or equal to the given target has been found.
+#[derive(Debug, Clone, Copy)]
+pub struct TargetCost<C>(pub C);
+
+impl<C: Ord> StopCondition<C> for TargetCost<C> {
+    fn should_stop(&mut self, progress: &SearchProgress<C>) -> Option<TerminationReason> {
+        progress
+            .best_cost
+            .as_ref()
+            .is_some_and(|c| *c <= self.0)
+            .then_some(TerminationReason::TargetCost)
+    }
+}
+
+/// Stops when the best leaf has not improved for the given number of nodes or the given duration,
+/// counted from the start of the traversal until a first leaf is found.
+#[derive(Debug, Clone, Copy)]
+pub struct Stagnation {
+    max_ops: Option<usize>,
+    max_time: Option<Duration>,
+}
+
+impl Stagnation {
+    /// Stops after visiting `max_ops` nodes without improvement.
+    pub fn ops(max_ops: usize) -> Self {
+        Self {
+            max_ops: Some(max_ops),
+            max_time: None,
+        }
+    }
+
+    /// Stops after `max_time` without improvement.
+    pub fn time(max_time: Duration) -> Self {
+        Self {
+            max_ops: None,
+            max_time: Some(max_time),
+        }
+    }
+}
+
+impl<C> StopCondition<C> for Stagnation {
+    fn should_stop(&mut self, progress: &SearchProgress<C>) -> Option<TerminationReason> {
+        let stagnated = self
+            .max_ops
+            .is_some_and(|n| progress.ops_since_improvement() >= n)
+            || self
+                .max_time
+                .is_some_and(|t| progress.time_since_improvement() >= t);
+        stagnated.then_some(TerminationReason::Stagnation)
+    }
+}
+
+/// Stops when the given number of leaves with a cost have been found.
+#[derive(Debug, Clone, Copy)]
+pub struct MaxSolutions(pub usize);
+
+impl<C> StopCondition<C> for MaxSolutions {
+    fn should_stop(&mut self, progress: &SearchProgress<C>) -> Option<TerminationReason> {
+        (progress.n_solutions >= self.0).then_some(TerminationReason::MaxSolutions)
+    }
+}
+
+/// Stops when the number of nodes waiting to be visited exceeds the given size.
+/// This condition is never met if the traversal does not report its frontier size.
+#[derive(Debug, Clone, Copy)]
+pub struct MaxFrontier(pub usize);
+
+impl<C> StopCondition<C> for MaxFrontier {
+    fn should_stop(&mut self, progress: &SearchProgress<C>) -> Option<TerminationReason> {
+        progress
+            .frontier_size
+            .is_some_and(|n| n > self.0)
+            .then_some(TerminationReason::MaxFrontier)
+    }
+}
+
+/// Stops when the given flag is set, e.g. from another thread.
+#[derive(Debug, Clone)]
+pub struct Cancel(pub Arc<AtomicBool>);
+
+impl<C> StopCondition<C> for Cancel {
+    fn should_stop(&mut self, _progress: &SearchProgress<C>) -> Option<TerminationReason> {
+        self.0
+            .load(Ordering::Relaxed)
+            .then_some(TerminationReason::Cancelled)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, atomic::AtomicBool};
+
+    use super::{
+        super::{bfs_until, dfs_until},
+        All, Any, Cancel, MaxFrontier, MaxOps, MaxSolutions, SearchProgress, Stagnation,
+        TargetCost,
+    };
+    use crate::traversal::functional::TerminationReason;
+
+    type Node = Vec<bool>;
+
+    #[test]
+    fn test_stop_conditions() {
+        let weights = [3, 4, 6, 5];
+        let profits = [2, 3, 2, 4];
+        let capacity = 8;
+        let total_items = weights.len();
+
+        let total = |n: &Node, values: &[u32]| -> u32 {
+            n.iter()
+                .copied()
+                .enumerate()
+                .map(|(i, b)| if b { values[i] } else { 0 })
+                .sum()
+        };
+
+        let successor_fn = |n: &Node| {
+            if n.len() == total_items {
+                return vec![];
+            }
+
+            let mut children = vec![];
+
+            let mut c1 = n.clone();
+            c1.push(false);
+            children.push(c1);
+
+            let next_idx = n.len();
+            if capacity >= total(n, &weights) + weights[next_idx] {
+                let mut c2 = n.clone();
+                c2.push(true);
+                children.push(c2);
+            }
+
+            children
+        };
+        let cost_fn = |n: &Node| Some(u32::MAX - total(n, &profits));
+        let leaf_check_fn = |n: &Node| n.len() == total_items;
+
+        let outcome = dfs_until(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            MaxOps(usize::MAX),
+        );
+        assert_eq!(outcome.termination, TerminationReason::Exhausted);
+        let (best_cost, _) = outcome.best().expect("DFS should find a valid solution");
+        assert_eq!(u32::MAX - best_cost, 6);
+
+        let target = u32::MAX - 5;
+        let outcome = dfs_until(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            Any(MaxOps(usize::MAX), TargetCost(target)),
+        );
+        assert_eq!(outcome.termination, TerminationReason::TargetCost);
+        assert!(outcome.best().is_some_and(|(c, _)| *c <= target));
+
+        let outcome = dfs_until(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            MaxSolutions(2),
+        );
+        assert_eq!(outcome.termination, TerminationReason::MaxSolutions);
+        assert_eq!(outcome.n_leaves, 2);
+
+        let outcome = dfs_until(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            Stagnation::ops(3),
+        );
+        assert_eq!(outcome.termination, TerminationReason::Stagnation);
+
+        let outcome = dfs_until(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            All(MaxOps(3), MaxOps(5)),
+        );
+        assert_eq!(outcome.termination, TerminationReason::MaxOps);
+        assert_eq!(outcome.n_visited, 5);
+
+        let outcome = dfs_until(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            |p: &SearchProgress<u32>| (p.n_ops >= 2).then_some(TerminationReason::MaxOps),
+        );
+        assert_eq!(outcome.n_visited, 2);
+
+        let outcome = bfs_until(vec![], successor_fn, leaf_check_fn, cost_fn, MaxFrontier(2));
+        assert_eq!(outcome.termination, TerminationReason::MaxFrontier);
+        assert!(outcome.best().is_none());
+
+        let cancelled = Arc::new(AtomicBool::new(true));
+        let outcome = dfs_until(
+            vec![],
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            Cancel(cancelled),
+        );
+        assert_eq!(outcome.termination, TerminationReason::Cancelled);
+        assert_eq!(outcome.n_visited, 0);
+    }
+}
@@ -2,17 +2,19 @@ use std::iter::FusedIterator;
 
 use crate::node::{Priority, TreeNode};
 
-use super::functional::gds_reach;
+use super::functional::{Frontier, GreedyContainer, Reachable, gds_reach};
+
+type GreedyState<N, P> = Reachable<GreedyContainer<N, fn(&N) -> Vec<N>, fn(&N) -> Option<P>>>;
 
 /// Greedy traversal implementation.
-pub struct GreedyTraversal<N> {
-    state: Box<dyn FusedIterator<Item = N>>,
+pub struct GreedyTraversal<N: Priority> {
+    state: GreedyState<N, N::Value>,
 }
 
 impl<C, N> GreedyTraversal<N>
 where
-    C: Copy + Ord + 'static,
-    N: TreeNode<Cost = C> + Priority + 'static,
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + Priority,
 {
     /// Creates a new `GreedyTraversal` instance that performs a greedy search starting from the given root node.
     ///
@@ -24,23 +26,42 @@ where
     /// # Returns
     /// A new `GreedyTraversal` iterator.
     pub fn new(root_node: N) -> Self {
-        let state = gds_reach(
-            root_node,
-            |n: &N| n.generate_child_nodes(),
-            |n: &N| n.priority(),
-        );
-        Self {
-            state: Box::new(state),
-        }
+        let successor_fn: fn(&N) -> Vec<N> = |n| n.generate_child_nodes();
+        let priority_fn: fn(&N) -> Option<N::Value> = |n| n.priority();
+        let state = gds_reach(root_node, successor_fn, priority_fn);
+        Self { state }
     }
 }
 
-impl<N> Iterator for GreedyTraversal<N> {
+impl<C, N> Iterator for GreedyTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + Priority,
+{
     type Item = N;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.state.next()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint()
+    }
 }
 
-impl<N> FusedIterator for GreedyTraversal<N> {}
+impl<C, N> FusedIterator for GreedyTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + Priority,
+{
+}
+
+impl<C, N> Frontier for GreedyTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + Priority,
+{
+    fn frontier_size(&self) -> Option<usize> {
+        self.state.frontier_size()
+    }
+}
@@ -0,0 +1,86 @@
+use std::iter::FusedIterator;
+
+use crate::node::{LowerBound, TreeNode};
+
+use super::functional::{Frontier, IterativeDeepeningAStarContainer, Reachable, idastar_reach};
+
+type IterativeDeepeningAStarState<C, N> = Reachable<
+    IterativeDeepeningAStarContainer<
+        C,
+        N,
+        fn(&N) -> Vec<N>,
+        fn(&N) -> bool,
+        fn(&N) -> Option<C>,
+        fn(&N) -> Option<C>,
+    >,
+>;
+
+/// Iterative Deepening A* traversal implementation.
+pub struct IterativeDeepeningAStarTraversal<N: TreeNode> {
+    state: IterativeDeepeningAStarState<N::Cost, N>,
+}
+
+impl<C, N> IterativeDeepeningAStarTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + LowerBound<Cost = C> + Clone,
+{
+    /// Creates a new `IterativeDeepeningAStarTraversal` instance that performs an iterative deepening A* search
+    /// starting from the given root node.
+    ///
+    /// Iterative deepening A* repeats depth-first searches bounded by a threshold on the lower bound of the nodes,
+    /// raising the threshold to the smallest lower bound that exceeded it after each iteration.
+    ///
+    /// # Parameters
+    /// - `root_node`: The starting node for the traversal.
+    ///
+    /// # Returns
+    /// A new `IterativeDeepeningAStarTraversal` iterator.
+    pub fn new(root_node: N) -> Self {
+        let successor_fn: fn(&N) -> Vec<N> = |n| n.generate_child_nodes();
+        let leaf_check_fn: fn(&N) -> bool = |n| n.is_leaf();
+        let cost_fn: fn(&N) -> Option<C> = |n| n.cost();
+        let lower_bound_fn: fn(&N) -> Option<C> = |n| n.cost_lb();
+        let state = idastar_reach(
+            root_node,
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+        );
+        Self { state }
+    }
+}
+
+impl<C, N> Iterator for IterativeDeepeningAStarTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + LowerBound<Cost = C> + Clone,
+{
+    type Item = N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.state.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint()
+    }
+}
+
+impl<C, N> FusedIterator for IterativeDeepeningAStarTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + LowerBound<Cost = C> + Clone,
+{
+}
+
+impl<C, N> Frontier for IterativeDeepeningAStarTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + LowerBound<Cost = C> + Clone,
+{
+    fn frontier_size(&self) -> Option<usize> {
+        self.state.frontier_size()
+    }
+}
@@ -0,0 +1,68 @@
+use std::iter::FusedIterator;
+
+use crate::node::TreeNode;
+
+use super::functional::{Frontier, IterativeDeepeningContainer, Reachable, iddfs_reach};
+
+type IterativeDeepeningState<N> = Reachable<IterativeDeepeningContainer<N, fn(&N) -> Vec<N>>>;
+
+/// Iterative Deepening Depth-First traversal implementation.
+pub struct IterativeDeepeningTraversal<N: TreeNode> {
+    state: IterativeDeepeningState<N>,
+}
+
+impl<C, N> IterativeDeepeningTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + Clone,
+{
+    /// Creates a new `IterativeDeepeningTraversal` instance that performs an iterative deepening depth-first search
+    /// starting from the given root node.
+    ///
+    /// Iterative deepening repeats depth-limited depth-first searches with a depth limit growing by one per iteration,
+    /// combining the level-by-level completeness of breadth-first search with the memory usage of depth-first search.
+    ///
+    /// # Parameters
+    /// - `root_node`: The starting node for the traversal.
+    ///
+    /// # Returns
+    /// A new `IterativeDeepeningTraversal` iterator.
+    pub fn new(root_node: N) -> Self {
+        let successor_fn: fn(&N) -> Vec<N> = |n| n.generate_child_nodes();
+        let state = iddfs_reach(root_node, successor_fn);
+        Self { state }
+    }
+}
+
+impl<C, N> Iterator for IterativeDeepeningTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + Clone,
+{
+    type Item = N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.state.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint()
+    }
+}
+
+impl<C, N> FusedIterator for IterativeDeepeningTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + Clone,
+{
+}
+
+impl<C, N> Frontier for IterativeDeepeningTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + Clone,
+{
+    fn frontier_size(&self) -> Option<usize> {
+        self.state.frontier_size()
+    }
+}
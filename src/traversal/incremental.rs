@@ -0,0 +1,109 @@
+use std::time::Duration;
+
+use crate::node::{IncrementalNode, LowerBound};
+
+use super::functional::{incremental_bbs, incremental_dfs, incremental_lds};
+
+/// Performs a depth-first search on a single node modified in place, starting from the given root node.
+///
+/// The moves of each node are explored in the order returned by [`IncrementalNode::moves`]. The node is moved
+/// down the tree with [`IncrementalNode::apply`] and back up with [`IncrementalNode::undo`], and is only cloned
+/// when a new best leaf is found.
+///
+/// # Parameters
+/// - `root_node`: The starting node for the search.
+/// - `max_ops`: The maximum number of nodes to visit.
+/// - `time_limit`: The maximum time allowed for the search.
+///
+/// # Returns
+/// The best leaf node found and its cost, if any.
+pub fn incremental_depth_first<N, C>(
+    root_node: N,
+    max_ops: usize,
+    time_limit: Duration,
+) -> Option<(C, N)>
+where
+    C: Copy + Ord,
+    N: IncrementalNode<Cost = C> + Clone,
+{
+    incremental_dfs(
+        root_node,
+        |n: &N| n.moves(),
+        |n: &mut N, m: &N::Move| n.apply(m),
+        |n: &mut N, m: &N::Move| n.undo(m),
+        |n: &N| n.is_leaf(),
+        |n: &N| n.cost(),
+        max_ops,
+        time_limit,
+    )
+}
+
+/// Performs a branch-and-bound search on a single node modified in place, starting from the given root node.
+///
+/// Like [`incremental_depth_first`], but nodes whose lower bound is not better than the best leaf found so far
+/// are not expanded.
+///
+/// # Parameters
+/// - `root_node`: The starting node for the search.
+/// - `max_ops`: The maximum number of nodes to visit.
+/// - `time_limit`: The maximum time allowed for the search.
+///
+/// # Returns
+/// The best leaf node found and its cost, if any.
+pub fn incremental_branch_and_bound<N, C>(
+    root_node: N,
+    max_ops: usize,
+    time_limit: Duration,
+) -> Option<(C, N)>
+where
+    C: Copy + Ord,
+    N: IncrementalNode<Cost = C> + LowerBound<Cost = C> + Clone,
+{
+    incremental_bbs(
+        root_node,
+        |n: &N| n.moves(),
+        |n: &mut N, m: &N::Move| n.apply(m),
+        |n: &mut N, m: &N::Move| n.undo(m),
+        |n: &N| n.is_leaf(),
+        |n: &N| IncrementalNode::cost(n),
+        |n: &N| n.cost_lb(),
+        max_ops,
+        time_limit,
+    )
+}
+
+/// Performs a limited discrepancy search on a single node modified in place, starting from the given root node.
+///
+/// Following any move but the first one returned by [`IncrementalNode::moves`] is a discrepancy. Paths are
+/// explored in order of increasing number of discrepancies, up to `max_discrepancy`.
+///
+/// # Parameters
+/// - `root_node`: The starting node for the search.
+/// - `max_discrepancy`: The maximum number of discrepancies allowed along a path.
+/// - `max_ops`: The maximum number of nodes to visit.
+/// - `time_limit`: The maximum time allowed for the search.
+///
+/// # Returns
+/// The best leaf node found and its cost, if any.
+pub fn incremental_limited_discrepancy<N, C>(
+    root_node: N,
+    max_discrepancy: usize,
+    max_ops: usize,
+    time_limit: Duration,
+) -> Option<(C, N)>
+where
+    C: Copy + Ord,
+    N: IncrementalNode<Cost = C> + Clone,
+{
+    incremental_lds(
+        root_node,
+        |n: &N| n.moves(),
+        |n: &mut N, m: &N::Move| n.apply(m),
+        |n: &mut N, m: &N::Move| n.undo(m),
+        |n: &N| n.is_leaf(),
+        |n: &N| n.cost(),
+        max_discrepancy,
+        max_ops,
+        time_limit,
+    )
+}
@@ -0,0 +1,134 @@
+use std::iter::FusedIterator;
+
+use crate::node::{Priority, TreeNode};
+
+use super::functional::{Frontier, LimitedDiscrepancyContainer, Reachable, dds_reach, lds_reach};
+
+type DiscrepancyState<N, P> =
+    Reachable<LimitedDiscrepancyContainer<N, fn(&N) -> Vec<N>, fn(&N) -> Option<P>>>;
+
+/// Limited Discrepancy traversal implementation.
+pub struct LimitedDiscrepancyTraversal<N: Priority> {
+    state: DiscrepancyState<N, N::Value>,
+}
+
+impl<C, N> LimitedDiscrepancyTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + Priority,
+{
+    /// Creates a new `LimitedDiscrepancyTraversal` instance that performs a limited discrepancy search starting from
+    /// the given root node.
+    ///
+    /// Limited discrepancy search ranks the children of each node by priority and explores paths in order of
+    /// increasing number of discrepancies, i.e. decisions that do not follow the best ranked child.
+    ///
+    /// # Parameters
+    /// - `root_node`: The starting node for the traversal.
+    /// - `max_discrepancy`: The maximum number of discrepancies allowed along a path.
+    ///
+    /// # Returns
+    /// A new `LimitedDiscrepancyTraversal` iterator.
+    pub fn new(root_node: N, max_discrepancy: usize) -> Self {
+        let successor_fn: fn(&N) -> Vec<N> = |n| n.generate_child_nodes();
+        let priority_fn: fn(&N) -> Option<N::Value> = |n| n.priority();
+        let state = lds_reach(root_node, successor_fn, priority_fn, max_discrepancy);
+        Self { state }
+    }
+}
+
+impl<C, N> Iterator for LimitedDiscrepancyTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + Priority,
+{
+    type Item = N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.state.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint()
+    }
+}
+
+impl<C, N> FusedIterator for LimitedDiscrepancyTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + Priority,
+{
+}
+
+impl<C, N> Frontier for LimitedDiscrepancyTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + Priority,
+{
+    fn frontier_size(&self) -> Option<usize> {
+        self.state.frontier_size()
+    }
+}
+
+/// Depth-bounded Discrepancy traversal implementation.
+pub struct DepthBoundedDiscrepancyTraversal<N: Priority> {
+    state: DiscrepancyState<N, N::Value>,
+}
+
+impl<C, N> DepthBoundedDiscrepancyTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + Priority,
+{
+    /// Creates a new `DepthBoundedDiscrepancyTraversal` instance that performs a depth-bounded discrepancy search
+    /// starting from the given root node.
+    ///
+    /// Depth-bounded discrepancy search ranks the children of each node by priority and explores paths in order of
+    /// increasing depth of their deepest discrepancy, following only the best ranked child below that depth.
+    ///
+    /// # Parameters
+    /// - `root_node`: The starting node for the traversal.
+    /// - `max_depth`: The maximum depth at which discrepancies are allowed.
+    ///
+    /// # Returns
+    /// A new `DepthBoundedDiscrepancyTraversal` iterator.
+    pub fn new(root_node: N, max_depth: usize) -> Self {
+        let successor_fn: fn(&N) -> Vec<N> = |n| n.generate_child_nodes();
+        let priority_fn: fn(&N) -> Option<N::Value> = |n| n.priority();
+        let state = dds_reach(root_node, successor_fn, priority_fn, max_depth);
+        Self { state }
+    }
+}
+
+impl<C, N> Iterator for DepthBoundedDiscrepancyTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + Priority,
+{
+    type Item = N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.state.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint()
+    }
+}
+
+impl<C, N> FusedIterator for DepthBoundedDiscrepancyTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + Priority,
+{
+}
+
+impl<C, N> Frontier for DepthBoundedDiscrepancyTraversal<N>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + Priority,
+{
+    fn frontier_size(&self) -> Option<usize> {
+        self.state.frontier_size()
+    }
+}
@@ -0,0 +1,81 @@
+use std::iter::FusedIterator;
+
+use crate::{ToF64, node::TreeNode};
+
+use super::functional::{Frontier, MonteCarloContainer, Reachable, mcts_reach};
+
+type MonteCarloState<C, N> =
+    Reachable<MonteCarloContainer<C, N, fn(&N) -> Vec<N>, fn(&N) -> bool, fn(&N) -> Option<C>>>;
+
+/// Monte Carlo Tree Search traversal implementation.
+pub struct MonteCarloTraversal<N: TreeNode> {
+    state: MonteCarloState<N::Cost, N>,
+}
+
+impl<C, N> MonteCarloTraversal<N>
+where
+    C: Copy + Ord + ToF64,
+    N: TreeNode<Cost = C> + Clone,
+{
+    /// Creates a new `MonteCarloTraversal` instance that performs a Monte Carlo tree search starting from the given
+    /// root node.
+    ///
+    /// Monte Carlo tree search grows a search tree by selecting nodes with the UCB1 rule, expanding one child and
+    /// performing a random rollout from it down to a leaf, whose cost is then backpropagated. Every leaf reached by a
+    /// rollout is yielded, so the traversal can be used with `find_best` and `traverse` like any other traversal.
+    ///
+    /// # Parameters
+    /// - `root_node`: The starting node for the traversal.
+    /// - `exploration`: The exploration constant of UCB1; `2f64.sqrt()` is a common choice.
+    /// - `seed`: The seed of the random number generator, making the traversal reproducible.
+    ///
+    /// # Returns
+    /// A new `MonteCarloTraversal` iterator.
+    pub fn new(root_node: N, exploration: f64, seed: u64) -> Self {
+        let successor_fn: fn(&N) -> Vec<N> = |n| n.generate_child_nodes();
+        let leaf_check_fn: fn(&N) -> bool = |n| n.is_leaf();
+        let cost_fn: fn(&N) -> Option<C> = |n| n.cost();
+        let state = mcts_reach(
+            root_node,
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            exploration,
+            seed,
+        );
+        Self { state }
+    }
+}
+
+impl<C, N> Iterator for MonteCarloTraversal<N>
+where
+    C: Copy + Ord + ToF64,
+    N: TreeNode<Cost = C> + Clone,
+{
+    type Item = N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.state.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint()
+    }
+}
+
+impl<C, N> FusedIterator for MonteCarloTraversal<N>
+where
+    C: Copy + Ord + ToF64,
+    N: TreeNode<Cost = C> + Clone,
+{
+}
+
+impl<C, N> Frontier for MonteCarloTraversal<N>
+where
+    C: Copy + Ord + ToF64,
+    N: TreeNode<Cost = C> + Clone,
+{
+    fn frontier_size(&self) -> Option<usize> {
+        self.state.frontier_size()
+    }
+}
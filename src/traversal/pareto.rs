@@ -0,0 +1,93 @@
+use std::iter::FusedIterator;
+
+use crate::node::{LowerBound, TreeNode};
+
+use super::functional::{
+    Frontier, Objectives, ParetoBranchAndBoundContainer, Reachable, pareto_bbs_reach,
+};
+
+type ParetoBranchAndBoundState<C, N> = Reachable<
+    ParetoBranchAndBoundContainer<
+        C,
+        N,
+        fn(&N) -> Vec<N>,
+        fn(&N) -> bool,
+        fn(&N) -> Option<C>,
+        fn(&N) -> Option<C>,
+    >,
+>;
+
+/// Multi-objective Branch-and-Bound traversal implementation.
+pub struct ParetoBranchAndBoundTraversal<N: TreeNode> {
+    state: ParetoBranchAndBoundState<N::Cost, N>,
+}
+
+impl<C, N> ParetoBranchAndBoundTraversal<N>
+where
+    C: Objectives,
+    N: TreeNode<Cost = C> + LowerBound<Cost = C>,
+{
+    /// Creates a new `ParetoBranchAndBoundTraversal` instance that performs a multi-objective branch-and-bound
+    /// search starting from the given root node.
+    ///
+    /// The cost of a node is a vector of objectives, e.g. a `(cost, time)` tuple, and its lower bound is a vector of
+    /// lower bounds on each objective. A node is pruned when its lower bounds are weakly dominated by the objectives of
+    /// a leaf found before. Use it with [`traverse_pareto`](super::traverse_pareto) to collect the Pareto front.
+    ///
+    /// Since [`TreeNode::Cost`] and [`LowerBound::Cost`] are `Copy`, the objectives must be a tuple or an array, e.g.
+    /// `[u32; 3]`. Objectives stored in a `Vec` are only supported by the functional
+    /// [`pareto_bbs_reach`](super::functional::pareto_bbs_reach).
+    ///
+    /// # Parameters
+    /// - `root_node`: The starting node for the traversal.
+    ///
+    /// # Returns
+    /// A new `ParetoBranchAndBoundTraversal` iterator.
+    pub fn new(root_node: N) -> Self {
+        let successor_fn: fn(&N) -> Vec<N> = |n| n.generate_child_nodes();
+        let leaf_check_fn: fn(&N) -> bool = |n| n.is_leaf();
+        let cost_fn: fn(&N) -> Option<C> = |n| n.cost();
+        let lower_bound_fn: fn(&N) -> Option<C> = |n| n.cost_lb();
+        let state = pareto_bbs_reach(
+            root_node,
+            successor_fn,
+            leaf_check_fn,
+            cost_fn,
+            lower_bound_fn,
+        );
+        Self { state }
+    }
+}
+
+impl<C, N> Iterator for ParetoBranchAndBoundTraversal<N>
+where
+    C: Objectives,
+    N: TreeNode<Cost = C> + LowerBound<Cost = C>,
+{
+    type Item = N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.state.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint()
+    }
+}
+
+impl<C, N> FusedIterator for ParetoBranchAndBoundTraversal<N>
+where
+    C: Objectives,
+    N: TreeNode<Cost = C> + LowerBound<Cost = C>,
+{
+}
+
+impl<C, N> Frontier for ParetoBranchAndBoundTraversal<N>
+where
+    C: Objectives,
+    N: TreeNode<Cost = C> + LowerBound<Cost = C>,
+{
+    fn frontier_size(&self) -> Option<usize> {
+        self.state.frontier_size()
+    }
+}
@@ -0,0 +1,41 @@
+use std::time::Duration;
+
+use crate::node::{LowerBound, TreeNode};
+
+use super::functional::{BranchAndBoundResult, pbbs_solve};
+
+/// Performs a branch-and-bound search on several threads starting from the given root node.
+///
+/// Each worker thread explores its own part of the tree depth-first, and idle workers steal the shallowest
+/// waiting nodes of the others. The cost of the best leaf is shared between the workers, so a leaf found by one of
+/// them prunes the branches of all of them.
+///
+/// # Parameters
+/// - `root_node`: The starting node for the search.
+/// - `n_threads`: The number of worker threads, or `0` to use the available parallelism.
+/// - `max_ops`: The maximum number of nodes to process, counted over all threads.
+/// - `time_limit`: The maximum time allowed for the search.
+///
+/// # Returns
+/// A [`BranchAndBoundResult`] holding the best leaf node and its cost, if any, along with the proof status.
+pub fn parallel_branch_and_bound<N, C>(
+    root_node: N,
+    n_threads: usize,
+    max_ops: usize,
+    time_limit: Duration,
+) -> BranchAndBoundResult<C, N>
+where
+    C: Copy + Ord + Send,
+    N: TreeNode<Cost = C> + LowerBound<Cost = C> + Send,
+{
+    pbbs_solve(
+        root_node,
+        |n: &N| n.generate_child_nodes(),
+        |n: &N| n.is_leaf(),
+        |n: &N| n.cost(),
+        |n: &N| n.cost_lb(),
+        n_threads,
+        max_ops,
+        time_limit,
+    )
+}
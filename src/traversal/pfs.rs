@@ -1,18 +1,34 @@
-use std::iter::FusedIterator;
+use std::{iter::FusedIterator, marker::PhantomData};
 
-use crate::node::{Priority, TreeNode};
+use crate::node::{Priority, StateKey, TreeNode};
 
-use super::functional::pfs_reach;
+use super::functional::{
+    ClosedSetContainer, Frontier, PriorityFirstContainer, Reachable, dedup_reach, pfs_reach,
+};
+
+type PriorityFirstState<N, P> =
+    Reachable<PriorityFirstContainer<N, fn(&N) -> Vec<N>, fn(&N) -> Option<P>, P>>;
+
+type PriorityFirstClosedSetState<N, P, K> = Reachable<
+    ClosedSetContainer<
+        PriorityFirstContainer<N, fn(&N) -> Vec<N>, fn(&N) -> Option<P>, P>,
+        K,
+        (),
+        fn(&N) -> K,
+        fn(&N),
+    >,
+>;
 
 /// Priority-First traversal implementation.
-pub struct PriorityFirstTraversal<N> {
-    state: Box<dyn FusedIterator<Item = N>>,
+pub struct PriorityFirstTraversal<N: Priority, S = PriorityFirstState<N, <N as Priority>::Value>> {
+    state: S,
+    _node: PhantomData<fn() -> N>,
 }
 
 impl<C, N> PriorityFirstTraversal<N>
 where
-    C: Copy + Ord + 'static,
-    N: TreeNode<Cost = C> + Priority + 'static,
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + Priority,
 {
     /// Creates a new `PriorityFirstTraversal` instance that performs a priority-first search starting from the given root node.
     ///
@@ -24,23 +40,60 @@ where
     /// # Returns
     /// A new `PriorityFirstTraversal` iterator.
     pub fn new(root_node: N) -> Self {
-        let state = pfs_reach(
-            root_node,
-            |n: &N| n.generate_child_nodes(),
-            |n: &N| n.priority(),
-        );
+        let successor_fn: fn(&N) -> Vec<N> = |n| n.generate_child_nodes();
+        let priority_fn: fn(&N) -> Option<N::Value> = |n| n.priority();
+        let state = pfs_reach(root_node, successor_fn, priority_fn);
         Self {
-            state: Box::new(state),
+            state,
+            _node: PhantomData,
         }
     }
 }
 
-impl<N> Iterator for PriorityFirstTraversal<N> {
+impl<C, N> PriorityFirstTraversal<N, PriorityFirstClosedSetState<N, N::Value, N::Key>>
+where
+    C: Copy + Ord,
+    N: TreeNode<Cost = C> + Priority + StateKey,
+{
+    /// Creates a new `PriorityFirstTraversal` instance like [`new`](PriorityFirstTraversal::new), but with duplicate
+    /// detection.
+    ///
+    /// Nodes whose state key has already been visited are skipped without being expanded, so each
+    /// state is visited at most once even if several paths of the tree lead to it.
+    ///
+    /// # Parameters
+    /// - `root_node`: The starting node for the traversal.
+    ///
+    /// # Returns
+    /// A new `PriorityFirstTraversal` iterator.
+    pub fn with_closed_set(root_node: N) -> Self {
+        let successor_fn: fn(&N) -> Vec<N> = |n| n.generate_child_nodes();
+        let priority_fn: fn(&N) -> Option<N::Value> = |n| n.priority();
+        let key_fn: fn(&N) -> N::Key = |n| n.state_key();
+        let state = dedup_reach(pfs_reach(root_node, successor_fn, priority_fn), key_fn);
+        Self {
+            state,
+            _node: PhantomData,
+        }
+    }
+}
+
+impl<N: Priority, S: Iterator<Item = N>> Iterator for PriorityFirstTraversal<N, S> {
     type Item = N;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.state.next()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint()
+    }
 }
 
-impl<N> FusedIterator for PriorityFirstTraversal<N> {}
+impl<N: Priority, S: FusedIterator<Item = N>> FusedIterator for PriorityFirstTraversal<N, S> {}
+
+impl<N: Priority, S: Frontier> Frontier for PriorityFirstTraversal<N, S> {
+    fn frontier_size(&self) -> Option<usize> {
+        self.state.frontier_size()
+    }
+}
@@ -0,0 +1,273 @@
+use std::{
+    panic,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread,
+};
+
+use crate::node::TreeNode;
+
+use super::{
+    common::{Traversal, traverse_observed},
+    functional::{
+        Any, Cancel, ExpansionObserver, Frontier, Incumbent, SearchObserver, SearchOutcome,
+        SearchProgress, SharedIncumbent, StopCondition, TerminationReason,
+    },
+};
+
+type RunFn<N, C> =
+    dyn Fn(N, SharedIncumbent<C>, &mut dyn StopCondition<C>) -> SearchOutcome<C, N> + Send + Sync;
+
+/// A traversal configuration raced by a [`Portfolio`].
+struct Strategy<N, C> {
+    name: String,
+    complete: bool,
+    run: Box<RunFn<N, C>>,
+}
+
+/// An observer offering the cost of every new best leaf to the shared incumbent.
+struct ShareIncumbent<C>(SharedIncumbent<C>);
+
+impl<N, C> ExpansionObserver<N> for ShareIncumbent<C> {}
+
+impl<N, C: Ord + Copy> SearchObserver<N, C> for ShareIncumbent<C> {
+    fn on_incumbent(&mut self, _node: &N, cost: C) {
+        self.0.offer(cost);
+    }
+}
+
+/// Cancels every strategy when dropped while its strategy is panicking, so that the other
+/// strategies do not run until their own stop condition is met.
+struct CancelOnPanic<'a>(&'a AtomicBool);
+
+impl Drop for CancelOnPanic<'_> {
+    fn drop(&mut self) {
+        if thread::panicking() {
+            self.0.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
+/// The outcome of a [`Portfolio`] run.
+#[derive(Debug, Clone)]
+pub struct PortfolioOutcome<C, N> {
+    /// The name and the outcome of each strategy, in the order they were added.
+    pub outcomes: Vec<(String, SearchOutcome<C, N>)>,
+    /// The index of the strategy that found the best leaf, if any leaf was found.
+    pub winner: Option<usize>,
+}
+
+impl<C, N> PortfolioOutcome<C, N> {
+    /// Returns the best leaf found by any strategy and its cost, if any.
+    pub fn best(&self) -> Option<&(C, N)> {
+        self.outcomes[self.winner?].1.best()
+    }
+
+    /// Returns the name of the strategy that found the best leaf, if any leaf was found.
+    pub fn winner_name(&self) -> Option<&str> {
+        Some(&self.outcomes[self.winner?].0)
+    }
+}
+
+/// A portfolio of traversals racing on separate threads over the same root node.
+///
+/// Each strategy builds its traversal from a clone of the root node and the incumbent shared by
+/// all strategies, so that e.g. a [`BranchAndBoundTraversal`](super::BranchAndBoundTraversal)
+/// created with `with_incumbent` prunes with the leaves found by the other strategies. Every
+/// strategy offers the cost of its best leaves to the shared incumbent.
+///
+/// All strategies stop as soon as one of them meets the stop condition, or a strategy added with
+/// [`add_complete`](Self::add_complete) exhausts its tree, which proves its best leaf optimal.
+pub struct Portfolio<N, C> {
+    strategies: Vec<Strategy<N, C>>,
+}
+
+impl<N, C> Default for Portfolio<N, C>
+where
+    N: TreeNode<Cost = C> + Clone + Send,
+    C: Copy + Ord + Send,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, C> Portfolio<N, C>
+where
+    N: TreeNode<Cost = C> + Clone + Send,
+    C: Copy + Ord + Send,
+{
+    /// Creates a new `Portfolio` without any strategy.
+    pub fn new() -> Self {
+        Self { strategies: vec![] }
+    }
+
+    /// Adds a strategy whose traversal may end without visiting the whole tree, e.g. a beam search.
+    ///
+    /// # Parameters
+    /// - `name`: The name reported when the strategy finds the best leaf.
+    /// - `factory`: A function creating the traversal from the root node and the shared incumbent.
+    ///
+    /// # Returns
+    /// The portfolio with the new strategy.
+    pub fn add<T, F>(self, name: impl Into<String>, factory: F) -> Self
+    where
+        T: Traversal<N> + Frontier,
+        F: Fn(N, SharedIncumbent<C>) -> T + Send + Sync + 'static,
+    {
+        self.push(name.into(), false, factory)
+    }
+
+    /// Adds a strategy whose traversal visits or prunes the whole tree, e.g. a branch-and-bound search.
+    ///
+    /// When such a strategy exhausts its tree, its best leaf is optimal and all strategies stop.
+    ///
+    /// # Parameters
+    /// - `name`: The name reported when the strategy finds the best leaf.
+    /// - `factory`: A function creating the traversal from the root node and the shared incumbent.
+    ///
+    /// # Returns
+    /// The portfolio with the new strategy.
+    pub fn add_complete<T, F>(self, name: impl Into<String>, factory: F) -> Self
+    where
+        T: Traversal<N> + Frontier,
+        F: Fn(N, SharedIncumbent<C>) -> T + Send + Sync + 'static,
+    {
+        self.push(name.into(), true, factory)
+    }
+
+    fn push<T, F>(mut self, name: String, complete: bool, factory: F) -> Self
+    where
+        T: Traversal<N> + Frontier,
+        F: Fn(N, SharedIncumbent<C>) -> T + Send + Sync + 'static,
+    {
+        let run = move |root: N, incumbent: SharedIncumbent<C>, stop: &mut dyn StopCondition<C>| {
+            let mut traversal = factory(root, incumbent.clone());
+            traverse_observed(
+                &mut traversal,
+                1,
+                |p: &SearchProgress<C>| stop.should_stop(p),
+                ShareIncumbent(incumbent),
+            )
+        };
+        self.strategies.push(Strategy {
+            name,
+            complete,
+            run: Box::new(run),
+        });
+        self
+    }
+
+    /// Races all strategies on separate threads starting from the given root node.
+    ///
+    /// # Parameters
+    /// - `root_node`: The starting node, cloned for each strategy.
+    /// - `stop_condition`: The condition to stop the traversals, cloned for each strategy. When it is met by one
+    ///   strategy, all strategies stop.
+    ///
+    /// # Returns
+    /// A [`PortfolioOutcome`] holding the outcome of each strategy and the index of the one that found the best leaf.
+    /// Among strategies finding leaves of the same cost, the one that found it first wins.
+    ///
+    /// If a strategy panics, all strategies stop and the panic is resumed on the calling thread.
+    pub fn run<S>(&self, root_node: N, stop_condition: S) -> PortfolioOutcome<C, N>
+    where
+        S: StopCondition<C> + Clone + Send,
+    {
+        let incumbent = SharedIncumbent::new();
+        let cancelled = Arc::new(AtomicBool::new(false));
+
+        let outcomes: Vec<_> = thread::scope(|scope| {
+            let handles: Vec<_> = self
+                .strategies
+                .iter()
+                .map(|strategy| {
+                    let root = root_node.clone();
+                    let incumbent = incumbent.clone();
+                    let cancelled = Arc::clone(&cancelled);
+                    let mut stop = Any(Cancel(Arc::clone(&cancelled)), stop_condition.clone());
+                    scope.spawn(move || {
+                        let _cancel_on_panic = CancelOnPanic(&cancelled);
+                        let outcome = (strategy.run)(root, incumbent, &mut stop);
+                        let stops_all = match outcome.termination {
+                            TerminationReason::Cancelled => false,
+                            TerminationReason::Exhausted => strategy.complete,
+                            _ => true,
+                        };
+                        if stops_all {
+                            cancelled.store(true, Ordering::Relaxed);
+                        }
+                        (strategy.name.clone(), outcome)
+                    })
+                })
+                .collect();
+            let results: Vec<_> = handles.into_iter().map(|h| h.join()).collect();
+            results
+                .into_iter()
+                .map(|r| r.unwrap_or_else(|e| panic::resume_unwind(e)))
+                .collect()
+        });
+
+        let winner = outcomes
+            .iter()
+            .enumerate()
+            .filter_map(|(i, (_, outcome))| {
+                let (cost, _) = outcome.best()?;
+                Some((*cost, outcome.time_to_best(), i))
+            })
+            .min()
+            .map(|(_, _, i)| i);
+
+        PortfolioOutcome { outcomes, winner }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::panic;
+
+    use super::Portfolio;
+    use crate::{
+        node::TreeNode,
+        traversal::{DepthFirstTraversal, MaxOps},
+    };
+
+    /// A node of an infinite chain, which a depth-first traversal never exhausts.
+    #[derive(Debug, Clone)]
+    struct Node(u64);
+
+    impl TreeNode for Node {
+        type Cost = u64;
+
+        fn is_leaf(&self) -> bool {
+            false
+        }
+
+        fn generate_child_nodes(&self) -> Vec<Self> {
+            vec![Node(self.0 + 1)]
+        }
+
+        fn cost(&self) -> Option<Self::Cost> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_portfolio_panic() {
+        let portfolio = Portfolio::new()
+            .add("dfs", |root, _| DepthFirstTraversal::new(root))
+            .add("failing", |root: Node, _| -> DepthFirstTraversal<Node> {
+                panic!("failing strategy {}", root.0)
+            });
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            portfolio.run(Node(0), MaxOps(usize::MAX))
+        }));
+        let payload = result.expect_err("the panic should be resumed");
+        assert_eq!(
+            payload.downcast_ref::<String>().map(String::as_str),
+            Some("failing strategy 0")
+        );
+    }
+}
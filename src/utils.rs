@@ -6,6 +6,11 @@
 pub struct ScoredItem<S: Ord, A>(S, A);
 
 impl<S: Ord, A> ScoredItem<S, A> {
+    /// Returns a reference to the score.
+    pub fn score(&self) -> &S {
+        &self.0
+    }
+
     /// Consumes the scored item and returns the inner associated value, discarding the score.
     pub fn into_item(self) -> A {
         self.1
@@ -43,3 +48,30 @@ impl<S: Ord, A> From<(S, A)> for ScoredItem<S, A> {
         ScoredItem(cost, item)
     }
 }
+
+/// A small seedable pseudo random number generator based on SplitMix64.
+///
+/// `Rng` is used internally by randomized traversal algorithms so that runs are reproducible
+/// for a given seed without depending on an external crate. It is not suitable for cryptographic use.
+pub struct Rng(u64);
+
+impl Rng {
+    /// Creates a new generator from the given seed.
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    /// Returns the next pseudo random `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a pseudo random index in `0..n`. `n` must be positive.
+    pub fn gen_index(&mut self, n: usize) -> usize {
+        ((u128::from(self.next_u64()) * n as u128) >> 64) as usize
+    }
+}